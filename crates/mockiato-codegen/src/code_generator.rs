@@ -1,3 +1,4 @@
+use crate::parse::mockable_attr::{AssociatedConst, AssociatedType};
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use std::fmt::Debug;
@@ -8,6 +9,11 @@ pub(crate) struct GenerateOptions {
     pub(crate) custom_struct_ident: Option<Ident>,
     pub(crate) force_static_lifetimes: bool,
     pub(crate) custom_trait_path: Option<Path>,
+    pub(crate) thread_safe: bool,
+    pub(crate) lenient: bool,
+    pub(crate) associated_types: Vec<AssociatedType>,
+    pub(crate) associated_consts: Vec<AssociatedConst>,
+    pub(crate) async_trait: bool,
 }
 
 #[cfg_attr(test, mockiato::mockable)]
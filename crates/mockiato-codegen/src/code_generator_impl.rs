@@ -1,38 +1,64 @@
 use crate::code_generator::{self, CodeGenerator};
 use crate::code_generator_impl::arguments::generate_arguments;
-use crate::code_generator_impl::constant::{arguments_ident, arguments_matcher_ident};
+use crate::code_generator_impl::associated_consts::{
+    associated_const_definitions, AssociatedConstDefinition,
+};
+use crate::code_generator_impl::associated_types::resolve_associated_types;
+use crate::code_generator_impl::constant::{
+    arguments_ident, arguments_matcher_ident, method_types_ident,
+};
 use crate::code_generator_impl::constant::{
     mock_lifetime, mock_lifetime_as_generic_param, mock_struct_ident, mod_ident,
+    statics_struct_ident,
 };
 use crate::code_generator_impl::drop_impl::generate_drop_impl;
+use crate::code_generator_impl::future::FutureKind;
 use crate::code_generator_impl::generics::get_matching_generics_for_method_inputs;
+use crate::code_generator_impl::method_types::generate_method_types;
 use crate::code_generator_impl::mock_struct::generate_mock_struct;
+use crate::code_generator_impl::recorded_arguments::generate_recorded_arguments;
+use crate::code_generator_impl::statics::{generate_statics_struct, resolve_self_type};
 use crate::code_generator_impl::trait_impl::generate_trait_impl;
 use crate::code_generator_impl::visibility::raise_visibility_by_one_level;
 use crate::parse::method_decl::MethodDecl;
+use crate::parse::mockable_attr::AssociatedType;
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::fmt::Debug;
-use syn::{parse_quote, Generics, Ident, Path, ReturnType, Type, Visibility, WherePredicate};
+use syn::{
+    parse_quote, GenericParam, Generics, Ident, Path, Type, TypeParam, Visibility, WherePredicate,
+};
 
 pub(crate) use crate::code_generator_impl::arguments_matcher::*;
 
 mod arguments;
 mod arguments_matcher;
+mod associated_consts;
+mod associated_types;
 mod bound_lifetimes;
 mod constant;
 mod debug_impl;
 mod drop_impl;
+mod future;
 mod generics;
 mod lifetime_rewriter;
+mod method_types;
 mod mock_struct;
+mod recorded_arguments;
+mod statics;
 mod trait_impl;
 mod util;
 mod visibility;
+mod with_fallback;
 
 pub(crate) trait ArgumentsMatcherGenerator: Debug {
-    fn generate(&self, method: &MethodDeclMetadata, visibility: &Visibility) -> TokenStream;
+    fn generate(
+        &self,
+        method: &MethodDeclMetadata,
+        parameters: &GenerateMockParameters,
+        visibility: &Visibility,
+    ) -> TokenStream;
 }
 
 #[derive(Debug)]
@@ -68,29 +94,49 @@ impl CodeGenerator for CodeGeneratorImpl {
             .custom_trait_path
             .unwrap_or_else(|| ident_to_path(&trait_decl.ident));
 
+        let generics = generics_for_trait_decl(trait_decl, static_lifetime_restriction);
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let mock_type: Type = parse_quote!(#mock_struct_ident #ty_generics);
+
+        let associated_types = options.associated_types;
+        let async_trait = options.async_trait;
+        let associated_consts =
+            associated_const_definitions(trait_decl, options.associated_consts, &associated_types);
+
         let methods = trait_decl
             .methods
             .iter()
             .cloned()
-            .map(|method_decl| map_method_decl_to_method_decl_metadata(method_decl, trait_decl))
+            .map(|mut method_decl| {
+                resolve_associated_types(&mut method_decl, &associated_types);
+                resolve_self_type(&mut method_decl, &mock_type);
+                map_method_decl_to_method_decl_metadata(method_decl, trait_decl, async_trait)
+            })
             .collect();
 
         let parameters = GenerateMockParameters {
             mock_struct_ident: mock_struct_ident.clone(),
             mod_ident: mod_ident(&mock_struct_ident),
-            generics: generics_for_trait_decl(trait_decl, static_lifetime_restriction),
+            statics_struct_ident: statics_struct_ident(&mock_struct_ident),
+            generics,
             methods,
             trait_path,
+            thread_safe: options.thread_safe,
+            lenient: options.lenient,
+            associated_types,
+            associated_consts,
         };
 
         let mock_struct = generate_mock_struct(trait_decl, &parameters);
 
+        let statics_struct = generate_statics_struct(trait_decl, &parameters);
+
         let trait_impl = generate_trait_impl(trait_decl, &parameters);
 
         let arguments: TokenStream = parameters
             .methods
             .iter()
-            .map(|method| self.generate_argument_structs(method, trait_decl))
+            .map(|method| self.generate_argument_structs(method, &parameters, trait_decl))
             .collect();
 
         let drop_impl = generate_drop_impl(trait_decl, &parameters);
@@ -101,6 +147,8 @@ impl CodeGenerator for CodeGeneratorImpl {
         quote! {
             #mock_struct
 
+            #statics_struct
+
             #trait_impl
 
             #drop_impl
@@ -118,17 +166,23 @@ impl CodeGeneratorImpl {
     fn generate_argument_structs(
         &self,
         method: &MethodDeclMetadata,
+        parameters: &GenerateMockParameters,
         trait_decl: &TraitDecl,
     ) -> proc_macro2::TokenStream {
         let visibility = raise_visibility_by_one_level(&trait_decl.visibility);
         let arguments = generate_arguments(method, &visibility);
-        let arguments_matcher = self
-            .arguments_matcher_generator
-            .generate(method, &visibility);
+        let recorded_arguments =
+            generate_recorded_arguments(method, &visibility, parameters.thread_safe);
+        let arguments_matcher =
+            self.arguments_matcher_generator
+                .generate(method, parameters, &visibility);
+        let method_types = generate_method_types(method, trait_decl, parameters, &visibility);
 
         quote! {
             #arguments
+            #recorded_arguments
             #arguments_matcher
+            #method_types
         }
     }
 }
@@ -137,9 +191,16 @@ impl CodeGeneratorImpl {
 pub(crate) struct GenerateMockParameters {
     pub(crate) mock_struct_ident: Ident,
     pub(crate) mod_ident: Ident,
+    /// The struct holding the expectations for methods without `self`
+    pub(crate) statics_struct_ident: Ident,
     pub(crate) generics: Generics,
     pub(crate) trait_path: Path,
     pub(crate) methods: Vec<MethodDeclMetadata>,
+    pub(crate) thread_safe: bool,
+    /// Unexpected calls return a fallback value instead of panicking
+    pub(crate) lenient: bool,
+    pub(crate) associated_types: Vec<AssociatedType>,
+    pub(crate) associated_consts: Vec<AssociatedConstDefinition>,
 }
 
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -147,8 +208,16 @@ pub(crate) struct MethodDeclMetadata {
     pub(crate) method_decl: MethodDecl,
     pub(crate) arguments_struct_ident: Ident,
     pub(crate) arguments_matcher_struct_ident: Ident,
+    /// The generic types (of the trait and the method) that are used by the method's arguments
     pub(crate) generics: Generics,
+    /// The type parameters of the method itself. Empty for methods that are not generic.
+    pub(crate) type_params: Vec<Ident>,
+    /// The struct describing the types of a generic method for each combination of type parameters
+    pub(crate) method_types_struct_ident: Ident,
     pub(crate) return_type: Type,
+    /// Set if the method is asynchronous, in which case
+    /// [`MethodDeclMetadata::return_type`] is the output type of the returned future.
+    pub(crate) future_kind: Option<FutureKind>,
 }
 
 fn ident_to_path(ident: &Ident) -> Path {
@@ -158,19 +227,43 @@ fn ident_to_path(ident: &Ident) -> Path {
 fn map_method_decl_to_method_decl_metadata(
     method_decl: MethodDecl,
     trait_decl: &TraitDecl,
+    async_trait: bool,
 ) -> MethodDeclMetadata {
+    let type_params: Vec<_> = method_decl
+        .generics
+        .type_params()
+        .map(|type_param| type_param.ident.clone())
+        .collect();
+
+    // Apart from `'static`, the bounds of the method's type parameters
+    // are not needed by the generated structs and are therefore omitted.
+    let mut trait_and_method_generics = trait_decl.generics.clone();
+    for ident in &type_params {
+        trait_and_method_generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident.clone())));
+        trait_and_method_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#ident: 'static));
+    }
+
     let generics =
-        get_matching_generics_for_method_inputs(&method_decl.inputs, &trait_decl.generics);
+        get_matching_generics_for_method_inputs(&method_decl.inputs, &trait_and_method_generics);
     let arguments_struct_ident = arguments_ident(&method_decl.ident);
     let arguments_matcher_struct_ident = arguments_matcher_ident(&method_decl.ident);
-    let return_type = return_type(&method_decl);
+    let method_types_struct_ident = method_types_ident(&method_decl.ident);
+    let (return_type, future_kind) = future::return_type(&method_decl, async_trait);
 
     MethodDeclMetadata {
         method_decl,
         generics,
+        type_params,
+        method_types_struct_ident,
         arguments_struct_ident,
         arguments_matcher_struct_ident,
         return_type,
+        future_kind,
     }
 }
 
@@ -195,10 +288,3 @@ fn get_static_lifetime_restriction() -> WherePredicate {
     let mock_lifetime = mock_lifetime();
     parse_quote!(#mock_lifetime: 'static)
 }
-
-fn return_type(method_decl: &MethodDecl) -> Type {
-    match &method_decl.output {
-        ReturnType::Default => parse_quote! { () },
-        ReturnType::Type(_, ty) => ty.as_ref().clone(),
-    }
-}
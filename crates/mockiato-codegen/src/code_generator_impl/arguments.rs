@@ -22,7 +22,8 @@ pub(crate) fn generate_arguments(
 
     let mut lifetime_rewriter =
         LifetimeRewriter::new(UniformLifetimeGenerator::new(arguments_lifetime()));
-    let arguments_fields = generate_arguments_fields(&mut lifetime_rewriter, &method_decl.inputs);
+    let arguments_fields =
+        generate_arguments_fields(&mut lifetime_rewriter, &method_decl.inputs, visibility);
 
     let mut generics = generics.clone();
     generics.params.push(arguments_lifetime_as_generic_param());
@@ -90,9 +91,13 @@ fn debug_impl_fields<'a>(
     })
 }
 
+/// Generates the fields of an arguments struct.
+/// The fields share the visibility of the struct, so that they can be read
+/// by closures passed to `MethodCallBuilder::returns_with`.
 fn generate_arguments_fields(
     lifetime_rewriter: &mut LifetimeRewriter<UniformLifetimeGenerator>,
     method_inputs: &MethodInputs,
+    visibility: &Visibility,
 ) -> TokenStream {
     method_inputs
         .args
@@ -103,7 +108,7 @@ fn generate_arguments_fields(
 
             visit_type_mut(lifetime_rewriter, &mut ty);
 
-            quote! { pub(super) #ident: #ty, }
+            quote! { #visibility #ident: #ty, }
         })
         .collect()
 }
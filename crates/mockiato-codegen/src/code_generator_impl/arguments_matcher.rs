@@ -1,12 +1,13 @@
 use super::bound_lifetimes::rewrite_lifetimes_incrementally;
 use super::constant::{
     arguments_lifetime, arguments_lifetime_as_generic_param, arguments_matcher_ident,
-    mock_lifetime, mock_lifetime_as_generic_param,
+    mock_lifetime, mock_lifetime_as_generic_param, recorded_arguments_ident,
 };
 use super::debug_impl::{generate_debug_impl, DebugImplField};
-use super::ArgumentsMatcherGenerator;
+use super::recorded_arguments::generate_record_arguments_method;
 use super::MethodDeclMetadata;
-use crate::code_generator_impl::util::ident_to_string_literal;
+use super::{ArgumentsMatcherGenerator, GenerateMockParameters};
+use crate::code_generator_impl::util::{ident_to_string_literal, thread_safety_bounds};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::{MethodArg, MethodInputs};
 use proc_macro2::TokenStream;
@@ -24,7 +25,12 @@ impl ArgumentsMatcherGeneratorImpl {
 }
 
 impl ArgumentsMatcherGenerator for ArgumentsMatcherGeneratorImpl {
-    fn generate(&self, method: &MethodDeclMetadata, visibility: &Visibility) -> TokenStream {
+    fn generate(
+        &self,
+        method: &MethodDeclMetadata,
+        parameters: &GenerateMockParameters,
+        visibility: &Visibility,
+    ) -> TokenStream {
         let MethodDeclMetadata {
             method_decl,
             generics,
@@ -35,11 +41,13 @@ impl ArgumentsMatcherGenerator for ArgumentsMatcherGeneratorImpl {
         let mut generics = generics.clone();
         generics.params.push(mock_lifetime_as_generic_param());
 
-        let arguments_matcher_fields = arguments_matcher_fields(&method_decl.inputs);
+        let arguments_matcher_fields =
+            arguments_matcher_fields(&method_decl.inputs, parameters.thread_safe);
         let (_, ty_generics, where_clause) = generics.split_for_impl();
 
         let display_impl = generate_display_impl(method_decl, &generics);
-        let arguments_matcher_impl = generate_arguments_matcher_impl(method, &generics);
+        let arguments_matcher_impl =
+            generate_arguments_matcher_impl(method, &generics, parameters.thread_safe);
 
         let debug_impl = generate_debug_impl(
             debug_impl_fields(method_decl),
@@ -96,6 +104,7 @@ fn generate_display_impl(method_decl: &MethodDecl, generics: &Generics) -> Token
 fn generate_arguments_matcher_impl(
     method: &MethodDeclMetadata,
     generics_with_mock_lifetime: &Generics,
+    thread_safe: bool,
 ) -> TokenStream {
     let MethodDeclMetadata {
         method_decl,
@@ -116,22 +125,31 @@ fn generate_arguments_matcher_impl(
         .push(arguments_lifetime_as_generic_param());
 
     let (impl_generics, _, _) = generics_with_arguments_lifetime.split_for_impl();
-    let (_, ty_generics, where_clause) = generics_with_mock_lifetime.split_for_impl();
+    let (record_arguments_impl_generics, ty_generics, where_clause) =
+        generics_with_mock_lifetime.split_for_impl();
     let (_, arguments_ty_generics, _) = arguments_struct_generics.split_for_impl();
 
-    let matches_argument_method = generate_matches_arguments_method_impl(method_decl);
+    let arguments_matcher_methods = generate_arguments_matcher_methods_impl(method_decl);
+    let record_arguments_method = generate_record_arguments_method(method, thread_safe);
+    let recorded_arguments_ident = recorded_arguments_ident(&method_decl.ident);
     let arguments_lifetime = arguments_lifetime();
 
     quote! {
         impl #impl_generics mockiato::internal::ArgumentsMatcher<#arguments_lifetime> for #arguments_matcher_ident #ty_generics #where_clause {
             type Arguments = #arguments_struct_ident #arguments_ty_generics;
 
-            #matches_argument_method
+            #arguments_matcher_methods
+
+            #record_arguments_method
+        }
+
+        impl #record_arguments_impl_generics mockiato::internal::RecordArguments for #arguments_matcher_ident #ty_generics #where_clause {
+            type RecordedArguments = #recorded_arguments_ident;
         }
     }
 }
 
-fn generate_matches_arguments_method_impl(method_decl: &MethodDecl) -> TokenStream {
+fn generate_arguments_matcher_methods_impl(method_decl: &MethodDecl) -> TokenStream {
     let args = &method_decl.inputs.args;
 
     let matches_argument_calls = if args.is_empty() {
@@ -140,10 +158,22 @@ fn generate_matches_arguments_method_impl(method_decl: &MethodDecl) -> TokenStre
         generate_matches_argument_calls(args)
     };
 
+    let argument_accepted_calls: TokenStream = args
+        .iter()
+        .map(|arg| {
+            let ident = &arg.ident;
+            quote! { self.#ident.argument_accepted(&args.#ident); }
+        })
+        .collect();
+
     quote! {
         fn matches_arguments(&self, args: &Self::Arguments) -> bool {
             #matches_argument_calls
         }
+
+        fn arguments_accepted(&self, args: &Self::Arguments) {
+            #argument_accepted_calls
+        }
     }
 }
 
@@ -158,8 +188,9 @@ fn generate_matches_argument_calls(args: &[MethodArg]) -> TokenStream {
     quote!(#matches_argument_calls)
 }
 
-fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
+fn arguments_matcher_fields(method_inputs: &MethodInputs, thread_safe: bool) -> TokenStream {
     let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(thread_safe);
     method_inputs
         .args
         .iter()
@@ -169,7 +200,7 @@ fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
             let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
 
             quote! {
-                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime>,
+                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #mock_lifetime>,
             }
         })
         .collect()
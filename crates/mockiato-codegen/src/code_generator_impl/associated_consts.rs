@@ -0,0 +1,72 @@
+use super::associated_types::resolve_associated_types_in_type;
+use super::util::doc_attribute;
+use crate::parse::mockable_attr::{AssociatedConst, AssociatedType};
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Lit, Type, Visibility};
+
+/// An associated constant of the trait together with the value specified for the mock
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedConstDefinition {
+    ident: Ident,
+    ty: Type,
+    value: Lit,
+}
+
+/// Pairs the specified values with the types of the constants declared by the trait.
+/// Associated types are resolved, since `Self::Item` is ambiguous on the mock struct.
+pub(super) fn associated_const_definitions(
+    trait_decl: &TraitDecl,
+    associated_consts: Vec<AssociatedConst>,
+    associated_types: &[AssociatedType],
+) -> Vec<AssociatedConstDefinition> {
+    associated_consts
+        .into_iter()
+        .filter_map(|AssociatedConst { ident, value }| {
+            let mut ty = trait_decl
+                .associated_consts
+                .iter()
+                .find(|associated_const| associated_const.ident == ident)?
+                .ty
+                .clone();
+            resolve_associated_types_in_type(&mut ty, associated_types);
+            Some(AssociatedConstDefinition { ident, ty, value })
+        })
+        .collect()
+}
+
+/// Generates the associated constants of the trait impl
+pub(super) fn generate_associated_consts_for_trait_impl(
+    associated_consts: &[AssociatedConstDefinition],
+) -> TokenStream {
+    associated_consts
+        .iter()
+        .map(|AssociatedConstDefinition { ident, ty, value }| {
+            quote! { const #ident: #ty = #value; }
+        })
+        .collect()
+}
+
+/// Generates inherent associated constants on the mock struct,
+/// so that the values can be accessed without importing the trait.
+pub(super) fn generate_associated_consts_for_mock_struct(
+    associated_consts: &[AssociatedConstDefinition],
+    trait_ident: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    associated_consts
+        .iter()
+        .map(|AssociatedConstDefinition { ident, ty, value }| {
+            let documentation = doc_attribute(format!(
+                "The value of `{}::{}` for this mock.",
+                trait_ident, ident
+            ));
+
+            quote! {
+                #documentation
+                #visibility const #ident: #ty = #value;
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::mockable_attr::AssociatedType;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit_mut::{visit_type_mut, VisitMut};
+use syn::{Path, PathArguments, QSelf, Type, TypePath};
+
+/// Generates the associated type definitions of the trait impl
+pub(super) fn generate_associated_types(associated_types: &[AssociatedType]) -> TokenStream {
+    associated_types
+        .iter()
+        .map(|AssociatedType { ident, ty }| quote! { type #ident = #ty; })
+        .collect()
+}
+
+/// Replaces references to the trait's associated types (`Self::Item` or `<Self as Trait>::Item`)
+/// in the signature of the given method with the types that were specified for the mock.
+/// The generated structs live outside of the trait impl, where `Self` is not available.
+pub(super) fn resolve_associated_types(
+    method_decl: &mut MethodDecl,
+    associated_types: &[AssociatedType],
+) {
+    if associated_types.is_empty() {
+        return;
+    }
+
+    let mut resolver = AssociatedTypeResolver { associated_types };
+
+    for argument in &mut method_decl.inputs.args {
+        resolver.visit_type_mut(&mut argument.ty);
+    }
+
+    resolver.visit_return_type_mut(&mut method_decl.output);
+    resolver.visit_generics_mut(&mut method_decl.generics);
+}
+
+/// Replaces references to the trait's associated types in the given type.
+pub(super) fn resolve_associated_types_in_type(ty: &mut Type, associated_types: &[AssociatedType]) {
+    AssociatedTypeResolver { associated_types }.visit_type_mut(ty);
+}
+
+struct AssociatedTypeResolver<'a> {
+    associated_types: &'a [AssociatedType],
+}
+
+impl<'a> AssociatedTypeResolver<'a> {
+    fn resolve(&self, type_path: &TypePath) -> Option<&'a Type> {
+        let TypePath { qself, path } = type_path;
+
+        let associated_type_segment = match qself {
+            None if path.segments.len() == 2 && path.segments[0].ident == "Self" => {
+                &path.segments[1]
+            }
+            Some(QSelf { ty, position, .. })
+                if is_self(ty) && *position + 1 == path.segments.len() =>
+            {
+                path.segments.last().unwrap()
+            }
+            _ => return None,
+        };
+
+        if let PathArguments::None = associated_type_segment.arguments {
+            self.associated_types
+                .iter()
+                .find(|associated_type| associated_type.ident == associated_type_segment.ident)
+                .map(|associated_type| &associated_type.ty)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> VisitMut for AssociatedTypeResolver<'a> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        let resolved_type = match ty {
+            Type::Path(type_path) => self.resolve(type_path),
+            _ => None,
+        };
+
+        match resolved_type {
+            Some(resolved_type) => *ty = resolved_type.clone(),
+            None => visit_type_mut(self, ty),
+        }
+    }
+}
+
+fn is_self(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { segments, .. },
+        }) => segments.len() == 1 && segments[0].ident == "Self",
+        _ => false,
+    }
+}
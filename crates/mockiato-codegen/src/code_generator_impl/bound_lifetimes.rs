@@ -2,9 +2,9 @@ use super::lifetime_rewriter::{IncrementalLifetimeGenerator, LifetimeRewriter};
 use syn::visit_mut::visit_type_mut;
 use syn::{BoundLifetimes, Lifetime, LifetimeDef, Type};
 
-pub(super) fn rewrite_lifetimes_incrementally(mut ty: &mut Type) -> Option<BoundLifetimes> {
+pub(super) fn rewrite_lifetimes_incrementally(ty: &mut Type) -> Option<BoundLifetimes> {
     let mut lifetime_rewriter = LifetimeRewriter::new(IncrementalLifetimeGenerator::default());
-    visit_type_mut(&mut lifetime_rewriter, &mut ty);
+    visit_type_mut(&mut lifetime_rewriter, ty);
 
     bound_lifetimes(lifetime_rewriter.generator.lifetimes)
 }
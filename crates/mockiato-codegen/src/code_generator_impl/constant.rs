@@ -40,6 +40,16 @@ pub(super) fn mock_struct_ident(trait_decl: &TraitDecl) -> Ident {
     )
 }
 
+/// Generates the identifier of the struct holding the expectations for methods without `self`
+pub(super) fn statics_struct_ident(mock_ident: &Ident) -> Ident {
+    const IDENTIFIER_SUFFIX: &str = "Statics";
+
+    Ident::new(
+        &format!("{}{}", mock_ident, IDENTIFIER_SUFFIX),
+        mock_ident.span(),
+    )
+}
+
 /// Generates a [`struct@Ident`] for the internal sub-mod
 /// for `Arguments` and `ArgumentsMatcher` impls for a mock struct.
 pub(super) fn mod_ident(mock_ident: &Ident) -> Ident {
@@ -60,7 +70,7 @@ pub(super) fn expect_method_ident(method_decl_ident: &Ident) -> Ident {
     const IDENTIFIER_PREFIX: &str = "expect_";
 
     Ident::new(
-        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident.to_string()),
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident),
         method_decl_ident.span(),
     )
 }
@@ -73,14 +83,22 @@ pub(super) fn expect_method_calls_in_order_ident(method_decl: &MethodDecl) -> Id
     Ident::new(
         &format!(
             "{}{}{}",
-            IDENTIFIER_PREFIX,
-            method_decl.ident.to_string(),
-            IDENTIFIER_SUFFIX
+            IDENTIFIER_PREFIX, method_decl.ident, IDENTIFIER_SUFFIX
         ),
         method_decl.ident.span(),
     )
 }
 
+/// Generates the identifier for a method returning the recorded calls to a method
+pub(super) fn calls_to_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "calls_to_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident),
+        method_decl_ident.span(),
+    )
+}
+
 /// Generates the generic parameter for a given index
 pub(super) fn generic_parameter_ident(index: usize) -> Ident {
     const IDENTIFIER_PREFIX: &str = "A";
@@ -118,3 +136,41 @@ pub(super) fn arguments_matcher_ident(method_ident: &Ident) -> Ident {
         method_ident.span(),
     )
 }
+
+/// Generates the identifer for the struct holding the recorded arguments of a call
+pub(super) fn recorded_arguments_ident(method_ident: &Ident) -> Ident {
+    const IDENTIFIER_SUFFIX: &str = "RecordedArguments";
+
+    Ident::new(
+        &format!(
+            "{}{}",
+            method_ident.to_string().to_camel_case(),
+            IDENTIFIER_SUFFIX
+        ),
+        method_ident.span(),
+    )
+}
+
+/// Generates the identifer for the `MethodTypes` struct of a generic method
+pub(super) fn method_types_ident(method_ident: &Ident) -> Ident {
+    const IDENTIFIER_SUFFIX: &str = "MethodTypes";
+
+    Ident::new(
+        &format!(
+            "{}{}",
+            method_ident.to_string().to_camel_case(),
+            IDENTIFIER_SUFFIX
+        ),
+        method_ident.span(),
+    )
+}
+
+/// Generates the identifier for a method configuring the fallback of a method
+pub(super) fn fallback_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "fallback_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident),
+        method_decl_ident.span(),
+    )
+}
@@ -11,6 +11,7 @@ pub(crate) fn generate_drop_impl(
     let verify_calls: TokenStream = trait_decl
         .methods
         .iter()
+        .filter(|method_decl| method_decl.has_receiver())
         .map(generate_verify_call)
         .collect();
 
@@ -28,7 +29,7 @@ pub(crate) fn generate_drop_impl(
     }
 }
 
-fn generate_verify_call(method_decl: &MethodDecl) -> TokenStream {
+pub(super) fn generate_verify_call(method_decl: &MethodDecl) -> TokenStream {
     let ident = &method_decl.ident;
 
     quote! {
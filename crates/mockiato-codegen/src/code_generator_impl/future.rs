@@ -0,0 +1,134 @@
+use crate::parse::method_decl::MethodDecl;
+use syn::{
+    parse_quote, GenericArgument, Path, PathArguments, ReturnType, Type, TypeParamBound, TypePath,
+    TypeTraitObject,
+};
+
+/// Describes how an asynchronous method returns its future.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) enum FutureKind {
+    /// The method is declared as `async fn`.
+    AsyncFn,
+    /// The method returns a `Pin<Box<dyn Future<Output = T>>>`,
+    /// which is how `#[async_trait]` desugars `async fn`s.
+    /// Only used with `#[mockable(async_trait)]`.
+    BoxedFuture,
+}
+
+/// Determines the kind of future returned by the method (if any)
+/// and the type of the value that the mock needs to return.
+/// Boxed futures are only unwrapped if `async_trait` is set, as they are returned as is otherwise.
+pub(super) fn return_type(
+    method_decl: &MethodDecl,
+    async_trait: bool,
+) -> (Type, Option<FutureKind>) {
+    let future_kind = method_decl.asyncness.map(|_| FutureKind::AsyncFn);
+
+    match &method_decl.output {
+        ReturnType::Default => (parse_quote! { () }, future_kind),
+        ReturnType::Type(_, ty) if future_kind.is_some() || !async_trait => {
+            (ty.as_ref().clone(), future_kind)
+        }
+        ReturnType::Type(_, ty) => match boxed_future_output_type(ty) {
+            Some(output_type) => (output_type.clone(), Some(FutureKind::BoxedFuture)),
+            None => (ty.as_ref().clone(), None),
+        },
+    }
+}
+
+/// Extracts `T` from a type of the form `Pin<Box<dyn Future<Output = T>>>`.
+fn boxed_future_output_type(ty: &Type) -> Option<&Type> {
+    let boxed_future = single_type_argument(ty, "Pin")?;
+    let future = single_type_argument(boxed_future, "Box")?;
+
+    match future {
+        Type::TraitObject(TypeTraitObject { bounds, .. }) => {
+            bounds.iter().find_map(|bound| match bound {
+                TypeParamBound::Trait(trait_bound) => future_output_type(&trait_bound.path),
+                TypeParamBound::Lifetime(_) => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn future_output_type(path: &Path) -> Option<&Type> {
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Future" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => {
+            arguments.args.iter().find_map(|argument| match argument {
+                GenericArgument::Binding(binding) if binding.ident == "Output" => Some(&binding.ty),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns `T` if the given type is `{ident}<T>` (ignoring the path leading up to `ident`).
+fn single_type_argument<'a>(ty: &'a Type, ident: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != ident {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn output_type_tokens(ty: &Type) -> Option<String> {
+        boxed_future_output_type(ty).map(|output_type| quote!(#output_type).to_string())
+    }
+
+    #[test]
+    fn extracts_output_type_of_boxed_future() {
+        let ty: Type = parse_quote!(Pin<Box<dyn Future<Output = String> + Send + 'async_trait>>);
+
+        assert_eq!(Some(quote!(String).to_string()), output_type_tokens(&ty));
+    }
+
+    #[test]
+    fn extracts_output_type_of_boxed_future_with_paths() {
+        let ty: Type =
+            parse_quote!(std::pin::Pin<std::boxed::Box<dyn std::future::Future<Output = ()> + 'a>>);
+
+        assert_eq!(Some(quote!(()).to_string()), output_type_tokens(&ty));
+    }
+
+    #[test]
+    fn ignores_types_that_are_not_boxed_futures() {
+        let ty: Type = parse_quote!(Pin<Box<dyn Iterator<Item = String>>>);
+
+        assert!(boxed_future_output_type(&ty).is_none());
+    }
+
+    #[test]
+    fn ignores_boxed_futures_that_are_not_pinned() {
+        let ty: Type = parse_quote!(Box<dyn Future<Output = String>>);
+
+        assert!(boxed_future_output_type(&ty).is_none());
+    }
+}
@@ -108,8 +108,7 @@ impl<'a> Visit<'a> for FindOverlappingGenericTypeIdents<'a> {
 
             let first_segment_is_generic_type = self
                 .generic_type_idents_filter
-                .get(first_segment_ident)
-                .is_some();
+                .contains(first_segment_ident);
 
             let first_segment_has_no_arguments = first_segment.arguments.is_empty();
 
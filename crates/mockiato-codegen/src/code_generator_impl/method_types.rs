@@ -0,0 +1,88 @@
+use super::constant::{mock_lifetime, mock_lifetime_as_generic_param};
+use super::mock_struct::rewrite_lifetimes_to_mock_lifetime;
+use super::util::thread_safety_bounds;
+use super::{GenerateMockParameters, MethodDeclMetadata};
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, GenericParam, Ident, Visibility};
+
+/// Generates the struct that implements `MethodTypes` for a generic method.
+/// Generic methods are stored type-erased by the mock, this struct is used
+/// to recover the concrete types of the method for a combination of type parameters.
+pub(super) fn generate_method_types(
+    method: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+    visibility: &Visibility,
+) -> TokenStream {
+    if method.type_params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let method_types_ident = &method.method_types_struct_ident;
+    let trait_type_params: Vec<_> = trait_decl
+        .generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect();
+
+    let method_types_struct = if trait_type_params.is_empty() {
+        quote! {
+            #[doc(hidden)]
+            #visibility struct #method_types_ident;
+        }
+    } else {
+        quote! {
+            #[doc(hidden)]
+            #visibility struct #method_types_ident<#(#trait_type_params),*>(
+                std::marker::PhantomData<fn() -> (#(#trait_type_params,)*)>
+            );
+        }
+    };
+
+    let mock_lifetime = mock_lifetime();
+
+    let mut impl_generics = trait_decl.generics.clone();
+    impl_generics.params.push(mock_lifetime_as_generic_param());
+    impl_generics
+        .params
+        .extend(method.type_params.iter().map(|ident| -> GenericParam {
+            parse_quote! { #ident: 'static }
+        }));
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+    let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
+
+    let mut arguments_matcher_generics = method.generics.clone();
+    arguments_matcher_generics
+        .params
+        .push(mock_lifetime_as_generic_param());
+    let (_, arguments_matcher_ty_generics, _) = arguments_matcher_generics.split_for_impl();
+    let arguments_matcher_ident = &method.arguments_matcher_struct_ident;
+
+    let type_params_key = type_params_key(&method.type_params);
+    let return_type = rewrite_lifetimes_to_mock_lifetime(&method.return_type);
+    let thread_safety_bounds = thread_safety_bounds(parameters.thread_safe);
+
+    quote! {
+        #method_types_struct
+
+        impl #impl_generics mockiato::internal::MethodTypes<#mock_lifetime, #type_params_key> for #method_types_ident #trait_ty_generics #where_clause {
+            type ArgumentsMatcher = #arguments_matcher_ident #arguments_matcher_ty_generics;
+            type ReturnValue = #return_type;
+            type ReturnValueGenerator = dyn mockiato::internal::ReturnValueGenerator<
+                Self::ArgumentsMatcher,
+                Self::ReturnValue
+            > #thread_safety_bounds + #mock_lifetime;
+        }
+    }
+}
+
+/// The type that identifies a combination of type parameters of a generic method.
+/// This is either the only type parameter or a tuple of all type parameters.
+pub(super) fn type_params_key(type_params: &[Ident]) -> TokenStream {
+    match type_params {
+        [type_param] => quote! { #type_param },
+        type_params => quote! { (#(#type_params),*) },
+    }
+}
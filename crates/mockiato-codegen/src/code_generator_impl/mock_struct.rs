@@ -1,14 +1,20 @@
+use super::associated_consts::generate_associated_consts_for_mock_struct;
 use super::bound_lifetimes::rewrite_lifetimes_incrementally;
 use super::constant::{
-    arguments_matcher_ident, expect_method_calls_in_order_ident, expect_method_ident,
-    generic_parameter_ident, mock_lifetime, mock_lifetime_as_generic_param,
+    arguments_matcher_ident, calls_to_method_ident, expect_method_calls_in_order_ident,
+    expect_method_ident, fallback_method_ident, generic_parameter_ident, mock_lifetime,
+    mock_lifetime_as_generic_param, recorded_arguments_ident,
 };
 use super::debug_impl::{generate_debug_impl, DebugImplField};
-use super::generics::get_matching_generics_for_method_inputs;
 use super::lifetime_rewriter::{LifetimeRewriter, UniformLifetimeGenerator};
+use super::method_types::type_params_key;
+use super::with_fallback::generate_with_fallback_method;
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
-use crate::code_generator_impl::util::doc_attribute;
+use crate::code_generator_impl::util::{
+    doc_attribute, phantom_data_type, static_mock_predicates, thread_safety_bounds,
+    trait_has_method,
+};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::MethodArg;
 use crate::parse::trait_decl::TraitDecl;
@@ -16,7 +22,9 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::visit_mut::visit_type_mut;
-use syn::{parse_quote, GenericParam, Ident, LitStr, Token, Type, TypeParam, WherePredicate};
+use syn::{
+    parse_quote, GenericParam, Generics, Ident, LitStr, Token, Type, TypeParam, WherePredicate,
+};
 
 type ArgumentsWithGenerics<'a> = &'a [(Ident, &'a MethodArg)];
 
@@ -25,34 +33,45 @@ pub(crate) fn generate_mock_struct(
     parameters: &'_ GenerateMockParameters,
 ) -> TokenStream {
     let mock_struct_ident = &parameters.mock_struct_ident;
-    let mod_ident = &parameters.mod_ident;
 
-    let method_fields: TokenStream = parameters
+    let methods: Vec<_> = parameters
         .methods
         .iter()
-        .map(|method| generate_method_field(method, mod_ident))
+        .filter(|method| method.method_decl.has_receiver())
         .collect();
 
-    let initializer_fields: TokenStream = parameters
-        .methods
+    let method_fields: TokenStream = methods
+        .iter()
+        .map(|method| generate_method_field(method, trait_decl, parameters))
+        .collect();
+
+    let initializer_fields: TokenStream = methods
         .iter()
         .map(|method| generate_initializer_field(method, mock_struct_ident))
         .collect();
 
-    let expect_methods: TokenStream = parameters
-        .methods
+    let expect_methods: TokenStream = methods
         .iter()
-        .map(|method| generate_expect_method(method, trait_decl, mod_ident))
+        .map(|method| generate_expect_method(method, trait_decl, parameters))
         .collect();
 
-    let expect_method_call_in_order_methods: TokenStream = trait_decl
-        .methods
+    let expect_method_call_in_order_methods: TokenStream = methods
         .iter()
-        .map(|method_decl| generate_expect_method_calls_in_order_method(trait_decl, method_decl))
+        .map(|method| generate_expect_method_calls_in_order_method(trait_decl, &method.method_decl))
         .collect();
 
-    let debug_impl_fields = parameters
-        .methods
+    let calls_to_methods: TokenStream = methods
+        .iter()
+        .map(|method| generate_calls_to_method(method, trait_decl, parameters))
+        .collect();
+
+    let verification_methods = generate_verification_methods(trait_decl, &methods);
+
+    let lenient_methods = generate_lenient_methods(trait_decl, &methods, parameters);
+
+    let with_fallback_method = generate_with_fallback_method(trait_decl, &methods, parameters);
+
+    let debug_impl_fields = methods
         .iter()
         .map(|method| debug_impl_field(&method.method_decl));
 
@@ -61,6 +80,12 @@ pub(crate) fn generate_mock_struct(
 
     let visibility = &trait_decl.visibility;
 
+    let associated_consts = generate_associated_consts_for_mock_struct(
+        &parameters.associated_consts,
+        &trait_decl.ident,
+        visibility,
+    );
+
     const GITHUB_REPOSITORY: &str = "https://github.com/myelin-ai/mockiato";
 
     let documentation = doc_attribute(format!(
@@ -71,29 +96,60 @@ pub(crate) fn generate_mock_struct(
         trait_decl.ident, GITHUB_REPOSITORY
     ));
 
+    let new_method = if parameters.lenient {
+        let allow_unexpected_calls =
+            generate_allow_unexpected_calls_body(&methods, quote! { mock });
+        quote! {
+            /// Creates a new mock with no expectations, which allows unexpected calls.
+            #visibility fn new() -> Self {
+                let mut mock = Self {
+                    #initializer_fields
+                    phantom_data: std::marker::PhantomData,
+                };
+                #allow_unexpected_calls
+                mock
+            }
+        }
+    } else {
+        quote! {
+            /// Creates a new mock with no expectations.
+            #visibility fn new() -> Self {
+                Self {
+                    #initializer_fields
+                    phantom_data: std::marker::PhantomData,
+                }
+            }
+        }
+    };
+
     let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
-    let mock_lifetime = mock_lifetime();
+
+    let phantom_data_type = phantom_data_type(&trait_decl.generics);
 
     quote! {
         #[derive(Clone)]
         #documentation
         #visibility struct #mock_struct_ident #ty_generics #where_clause {
             #method_fields
-            phantom_data: std::marker::PhantomData<&#mock_lifetime ()>,
+            phantom_data: #phantom_data_type,
         }
 
         impl #impl_generics #mock_struct_ident #ty_generics #where_clause {
-            /// Creates a new mock with no expectations.
-            #visibility fn new() -> Self {
-                Self {
-                    #initializer_fields
-                    phantom_data: std::marker::PhantomData,
-                }
-            }
+            #associated_consts
+
+            #new_method
+
+            #with_fallback_method
 
             #expect_methods
 
             #expect_method_call_in_order_methods
+
+            #calls_to_methods
+
+            #verification_methods
+
+            #lenient_methods
         }
 
         #debug_impl
@@ -107,49 +163,111 @@ pub(crate) fn generate_mock_struct(
     }
 }
 
-fn generate_method_field(
+pub(super) fn generate_method_field(
     MethodDeclMetadata {
         method_decl: MethodDecl { ident, .. },
         arguments_matcher_struct_ident,
         generics,
+        type_params,
+        method_types_struct_ident,
         return_type,
         ..
     }: &MethodDeclMetadata,
-    mod_ident: &Ident,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
 ) -> TokenStream {
+    let mod_ident = &parameters.mod_ident;
+    let mock_lifetime = mock_lifetime();
+
+    if !type_params.is_empty() {
+        let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
+        let any_method_type = if parameters.thread_safe {
+            let thread_safety_bounds = thread_safety_bounds(parameters.thread_safe);
+            quote! { , dyn mockiato::internal::AnyMethod<#mock_lifetime> #thread_safety_bounds + #mock_lifetime }
+        } else {
+            TokenStream::new()
+        };
+
+        return quote! {
+            #ident: mockiato::internal::GenericMethod<#mock_lifetime, #mod_ident::#method_types_struct_ident #trait_ty_generics #any_method_type>,
+        };
+    }
+
+    let method_type_arguments = method_type_arguments(
+        arguments_matcher_struct_ident,
+        generics,
+        return_type,
+        parameters,
+    );
+
+    quote! {
+        #ident: mockiato::internal::Method<#mock_lifetime, #method_type_arguments>,
+    }
+}
+
+/// Generates the type arguments of a non-generic method: the arguments matcher, the return type
+/// and (for thread-safe mocks) the return value generator.
+fn method_type_arguments(
+    arguments_matcher_struct_ident: &Ident,
+    generics: &Generics,
+    return_type: &Type,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let mod_ident = &parameters.mod_ident;
     let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
 
     let mut generics = generics.clone();
     generics.params.push(mock_lifetime_as_generic_param());
     let (_, ty_generics, _) = generics.split_for_impl();
 
-    let mock_lifetime = mock_lifetime();
+    let arguments_matcher_type =
+        quote! { #mod_ident::#arguments_matcher_struct_ident #ty_generics };
+    let return_value_generator_type =
+        return_value_generator_type(&arguments_matcher_type, &return_type, parameters);
 
-    quote! {
-        #ident: mockiato::internal::Method<#mock_lifetime, #mod_ident::#arguments_matcher_struct_ident #ty_generics, #return_type>,
+    quote! { #arguments_matcher_type, #return_type #return_value_generator_type }
+}
+
+/// Generates the type of the return value generators stored by a method,
+/// which only differs from the default for thread-safe mocks.
+fn return_value_generator_type(
+    arguments_matcher_type: &TokenStream,
+    return_type: &Type,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if parameters.thread_safe {
+        let mock_lifetime = mock_lifetime();
+        let thread_safety_bounds = thread_safety_bounds(parameters.thread_safe);
+        quote! {
+            , dyn mockiato::internal::ReturnValueGenerator<#arguments_matcher_type, #return_type> #thread_safety_bounds + #mock_lifetime
+        }
+    } else {
+        TokenStream::new()
     }
 }
 
-fn generate_initializer_field(
+pub(super) fn generate_initializer_field(
     method: &MethodDeclMetadata,
     mock_struct_ident: &Ident,
 ) -> TokenStream {
     let method_ident = &method.method_decl.ident;
     let name = LitStr::new(
-        &format!(
-            "{}::{}",
-            mock_struct_ident.to_string(),
-            method_ident.to_string()
-        ),
+        &format!("{}::{}", mock_struct_ident, method_ident),
         Span::call_site(),
     );
 
-    quote! {
-        #method_ident: mockiato::internal::Method::new(#name),
+    if method.type_params.is_empty() {
+        quote! {
+            #method_ident: mockiato::internal::Method::new(#name),
+        }
+    } else {
+        quote! {
+            #method_ident: mockiato::internal::GenericMethod::new(#name),
+        }
     }
 }
 
-fn generate_expect_method(
+pub(super) fn generate_expect_method(
     MethodDeclMetadata {
         return_type,
         method_decl:
@@ -158,16 +276,19 @@ fn generate_expect_method(
                 inputs,
                 ..
             },
+        generics: arguments_struct_generics,
+        type_params,
         ..
     }: &MethodDeclMetadata,
     TraitDecl {
         visibility,
-        generics,
+        generics: trait_generics,
         ident: trait_ident,
         ..
     }: &TraitDecl,
-    mod_ident: &Ident,
+    parameters: &GenerateMockParameters,
 ) -> TokenStream {
+    let mod_ident = &parameters.mod_ident;
     let expect_method_ident = expect_method_ident(method_ident);
 
     let arguments_with_generics: Vec<_> = inputs
@@ -177,10 +298,19 @@ fn generate_expect_method(
         .map(|(index, argument)| (generic_parameter_ident(index), argument))
         .collect();
 
-    let arguments: TokenStream = arguments_with_generics
-        .iter()
-        .map(generate_argument)
-        .collect();
+    let is_generic_method = !type_params.is_empty();
+
+    let arguments: TokenStream = if is_generic_method {
+        arguments_with_generics
+            .iter()
+            .map(|(_, argument)| generate_argument_for_generic_method(argument, parameters))
+            .collect()
+    } else {
+        arguments_with_generics
+            .iter()
+            .map(generate_argument)
+            .collect()
+    };
 
     let arguments_matcher_ident = arguments_matcher_ident(method_ident);
     let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
@@ -207,15 +337,40 @@ panicking if the function was not called by the time the object goes out of scop
         trait_ident, method_ident,
     ));
 
-    let mut arguments_struct_generics = get_matching_generics_for_method_inputs(inputs, generics);
+    let mut arguments_struct_generics = arguments_struct_generics.clone();
     arguments_struct_generics
         .params
         .push(mock_lifetime_as_generic_param());
-    let generics = argument_generics(&arguments_with_generics);
-    let where_clause = where_clause(&arguments_with_generics);
 
-    let (_, ty_generics, _) = arguments_struct_generics.split_for_impl();
     let mock_lifetime = mock_lifetime();
+    let (generics, where_clause, add_expected_call_turbofish) = if is_generic_method {
+        let static_mock_predicates = static_mock_predicates(trait_generics);
+        let type_params_key = type_params_key(type_params);
+        (
+            quote! { #(#type_params: 'static),* },
+            quote! { #(#static_mock_predicates),* },
+            quote! { ::<#type_params_key> },
+        )
+    } else {
+        let generics = argument_generics(&arguments_with_generics);
+        let where_clause = where_clause(&arguments_with_generics, parameters.thread_safe);
+        (
+            quote! { #generics },
+            quote! { #where_clause },
+            TokenStream::new(),
+        )
+    };
+
+    let (_, ty_generics, _) = arguments_struct_generics.split_for_impl();
+    let arguments_matcher_type = quote! { #mod_ident::#arguments_matcher_ident #ty_generics };
+    let return_value_generator_type = if is_generic_method {
+        let thread_safety_bounds = thread_safety_bounds(parameters.thread_safe);
+        quote! {
+            , dyn mockiato::internal::ReturnValueGenerator<#arguments_matcher_type, #return_type> #thread_safety_bounds + #mock_lifetime
+        }
+    } else {
+        return_value_generator_type(&arguments_matcher_type, &return_type, parameters)
+    };
 
     quote! {
         #must_use_annotation
@@ -226,13 +381,14 @@ panicking if the function was not called by the time the object goes out of scop
         ) -> mockiato::MethodCallBuilder<
             #mock_lifetime,
             '_,
-            #mod_ident::#arguments_matcher_ident #ty_generics,
+            #arguments_matcher_type,
             #return_type
+            #return_value_generator_type
         > where #where_clause
         {
             #[allow(dead_code)]
             let argument = mockiato::Argument::internal_new();
-            self.#method_ident.add_expected_call(
+            self.#method_ident.add_expected_call#add_expected_call_turbofish(
                 #mod_ident::#arguments_matcher_ident {
                     #expected_parameters
                     phantom_data: std::marker::PhantomData,
@@ -242,7 +398,7 @@ panicking if the function was not called by the time the object goes out of scop
     }
 }
 
-fn generate_expect_method_calls_in_order_method(
+pub(super) fn generate_expect_method_calls_in_order_method(
     trait_decl: &TraitDecl,
     method_decl: &MethodDecl,
 ) -> TokenStream {
@@ -266,7 +422,251 @@ fn generate_expect_method_calls_in_order_method(
     }
 }
 
-fn debug_impl_field(method_decl: &MethodDecl) -> DebugImplField<'_> {
+/// Generates `verify`, `try_verify` and `checkpoint`, which verify the expected calls
+/// before the mock goes out of scope. Each of them is omitted if the trait has a method with that name.
+fn generate_verification_methods(
+    trait_decl: &TraitDecl,
+    methods: &[&MethodDeclMetadata],
+) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let method_idents: Vec<_> = methods
+        .iter()
+        .map(|method| &method.method_decl.ident)
+        .collect();
+
+    let verify_result = quote! {{
+        #[allow(unused_mut)]
+        let mut call_errors = std::vec::Vec::new();
+        #[allow(unused_mut)]
+        let mut verification_errors = std::vec::Vec::new();
+
+        #(
+            call_errors.extend(self.#method_idents.call_errors());
+            verification_errors.extend(self.#method_idents.verification_errors());
+        )*
+
+        mockiato::internal::mock_result(call_errors, verification_errors)
+    }};
+
+    let verify_method = if trait_has_method(trait_decl, "verify") {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Verifies that all expected calls were made so far and that no unexpected calls were made,
+            /// without panicking.
+            #visibility fn verify(&self) -> std::result::Result<(), mockiato::MockError> {
+                #verify_result
+            }
+        }
+    };
+
+    let try_verify_method = if trait_has_method(trait_decl, "try_verify") {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Verifies the mock without panicking, the same as `verify`.
+            #visibility fn try_verify(&self) -> std::result::Result<(), mockiato::MockError> {
+                #verify_result
+            }
+        }
+    };
+
+    let checkpoint_method = if trait_has_method(trait_decl, "checkpoint") {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Verifies that all expected calls were made so far and removes them afterwards,
+            /// so that new calls can be expected.
+            ///
+            /// # Panics
+            /// Panics if the expected calls were not satisfied or if unexpected calls were made.
+            #visibility fn checkpoint(&mut self) {
+                let result = #verify_result;
+
+                #( self.#method_idents.clear(); )*
+
+                mockiato::internal::mock_result_unwrap(result);
+            }
+        }
+    };
+
+    quote! {
+        #verify_method
+
+        #try_verify_method
+
+        #checkpoint_method
+    }
+}
+
+/// Generates `allow_unexpected_calls`, `unexpected_calls` and a `fallback_*` method for
+/// each non-generic method. Generic methods always panic on unexpected calls.
+/// `allow_unexpected_calls` and `unexpected_calls` are omitted if the trait has a method with that name.
+fn generate_lenient_methods(
+    trait_decl: &TraitDecl,
+    methods: &[&MethodDeclMetadata],
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let non_generic_methods: Vec<_> = methods
+        .iter()
+        .filter(|method| method.type_params.is_empty())
+        .collect();
+
+    let method_idents: Vec<_> = non_generic_methods
+        .iter()
+        .map(|method| &method.method_decl.ident)
+        .collect();
+
+    let fallback_methods: TokenStream = non_generic_methods
+        .iter()
+        .map(|method| generate_fallback_method(method, trait_decl, parameters))
+        .collect();
+
+    let allow_unexpected_calls_method = if trait_has_method(trait_decl, "allow_unexpected_calls") {
+        TokenStream::new()
+    } else {
+        let allow_unexpected_calls = generate_allow_unexpected_calls_body(methods, quote! { self });
+        quote! {
+            /// Allows calls that don't match any expected call.
+            /// Unexpected calls return the fallback configured using the `fallback_*` methods or
+            /// [`Default::default()`] if the return type implements [`Default`].
+            /// Methods that return a type without a [`Default`] implementation
+            /// still panic if no fallback was configured.
+            #visibility fn allow_unexpected_calls(&mut self) {
+                #allow_unexpected_calls
+            }
+        }
+    };
+
+    let unexpected_calls_method = if trait_has_method(trait_decl, "unexpected_calls") {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Returns the unexpected calls that were answered with a fallback value.
+            #visibility fn unexpected_calls(&self) -> std::vec::Vec<mockiato::CallError> {
+                #[allow(unused_mut)]
+                let mut unexpected_calls = std::vec::Vec::new();
+                #( unexpected_calls.extend(self.#method_idents.unexpected_calls()); )*
+                unexpected_calls
+            }
+        }
+    };
+
+    quote! {
+        #allow_unexpected_calls_method
+
+        #unexpected_calls_method
+
+        #fallback_methods
+    }
+}
+
+/// Generates the statements that allow unexpected calls to the non-generic methods of `receiver`.
+fn generate_allow_unexpected_calls_body(
+    methods: &[&MethodDeclMetadata],
+    receiver: TokenStream,
+) -> TokenStream {
+    let method_idents = methods
+        .iter()
+        .filter(|method| method.type_params.is_empty())
+        .map(|method| &method.method_decl.ident);
+
+    quote! {
+        #[allow(unused_imports)]
+        use mockiato::internal::{DefaultFallback as _, NoDefaultFallback as _};
+
+        #(
+            let default_fallback = (&#receiver.#method_idents.default_fallback_factory()).default_fallback();
+            #receiver.#method_idents.allow_unexpected_calls(default_fallback);
+        )*
+    }
+}
+
+fn generate_fallback_method(
+    MethodDeclMetadata {
+        method_decl: MethodDecl { ident, .. },
+        arguments_matcher_struct_ident,
+        generics,
+        return_type,
+        ..
+    }: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let documentation = doc_attribute(format!(
+        "Configures the return value of unexpected calls to [`{0}::{1}`].
+The fallback is only used once unexpected calls are allowed.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+        trait_decl.ident, ident,
+    ));
+
+    let visibility = &trait_decl.visibility;
+    let fallback_method_ident = fallback_method_ident(ident);
+    let mock_lifetime = mock_lifetime();
+    let method_type_arguments = method_type_arguments(
+        arguments_matcher_struct_ident,
+        generics,
+        return_type,
+        parameters,
+    );
+
+    quote! {
+        #documentation
+        #visibility fn #fallback_method_ident(&mut self) -> mockiato::FallbackBuilder<#mock_lifetime, '_, #method_type_arguments> {
+            self.#ident.fallback()
+        }
+    }
+}
+
+fn generate_calls_to_method(
+    method: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let method_decl = &method.method_decl;
+    let documentation = doc_attribute(format!(
+        "Returns the calls that were made to [`{0}::{1}`] so far.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+        trait_decl.ident, method_decl.ident,
+    ));
+
+    let visibility = &trait_decl.visibility;
+    let mod_ident = &parameters.mod_ident;
+
+    let ident = calls_to_method_ident(&method_decl.ident);
+    let method_ident = &method_decl.ident;
+    let recorded_arguments_ident = recorded_arguments_ident(method_ident);
+    let recorded_arguments_type = quote! { #mod_ident::#recorded_arguments_ident };
+
+    if method.type_params.is_empty() {
+        quote! {
+            #documentation
+            #visibility fn #ident(&self) -> mockiato::CallHistory<#recorded_arguments_type> {
+                self.#method_ident.call_history()
+            }
+        }
+    } else {
+        let type_params = &method.type_params;
+        let type_params_key = type_params_key(type_params);
+        let static_mock_predicates = static_mock_predicates(&trait_decl.generics);
+
+        quote! {
+            #documentation
+            #visibility fn #ident<#(#type_params: 'static),*>(&self) -> mockiato::CallHistory<#recorded_arguments_type>
+            where #(#static_mock_predicates),*
+            {
+                self.#method_ident.call_history::<#type_params_key>()
+            }
+        }
+    }
+}
+
+pub(super) fn debug_impl_field(method_decl: &MethodDecl) -> DebugImplField<'_> {
     let ident = &method_decl.ident;
     DebugImplField {
         ident,
@@ -281,11 +681,14 @@ fn is_empty_return_value(return_type: &Type) -> bool {
     }
 }
 
-fn where_clause(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<WherePredicate, Token![,]> {
+fn where_clause(
+    arguments: ArgumentsWithGenerics<'_>,
+    thread_safe: bool,
+) -> Punctuated<WherePredicate, Token![,]> {
     arguments
         .iter()
         .map(|(generic_type_ident, method_argument)| {
-            where_clause_predicate(generic_type_ident, method_argument)
+            where_clause_predicate(generic_type_ident, method_argument, thread_safe)
         })
         .collect()
 }
@@ -293,13 +696,15 @@ fn where_clause(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<WherePredica
 fn where_clause_predicate(
     generic_type_ident: &Ident,
     method_argument: &MethodArg,
+    thread_safe: bool,
 ) -> WherePredicate {
     let mut ty = method_argument.ty.clone();
     let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
     let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(thread_safe);
 
     parse_quote! {
-        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime
+        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #mock_lifetime
     }
 }
 
@@ -320,7 +725,27 @@ fn generate_argument((generic_type_ident, method_argument): &(Ident, &MethodArg)
     }
 }
 
-fn rewrite_lifetimes_to_mock_lifetime(ty: &Type) -> Type {
+/// Generates an argument of an `expect_*` method for a generic method.
+/// The argument matcher is an `impl Trait` (instead of a generic parameter),
+/// so that the method's type parameters are the only ones that need to be specified explicitly.
+fn generate_argument_for_generic_method(
+    method_argument: &MethodArg,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let argument_ident = &method_argument.ident;
+    let mut ty = method_argument.ty.clone();
+    let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
+    let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(parameters.thread_safe);
+
+    quote! {
+        #argument_ident: impl mockiato::internal::ArgumentMatcherFactory<
+            Matcher = impl #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #mock_lifetime
+        >,
+    }
+}
+
+pub(super) fn rewrite_lifetimes_to_mock_lifetime(ty: &Type) -> Type {
     let mut ty = ty.clone();
     let mut lifetime_rewriter =
         LifetimeRewriter::new(UniformLifetimeGenerator::new(mock_lifetime()));
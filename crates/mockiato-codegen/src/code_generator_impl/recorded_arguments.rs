@@ -0,0 +1,253 @@
+use super::constant::recorded_arguments_ident;
+use super::debug_impl::{generate_debug_impl, DebugImplField};
+use super::util::thread_safety_bounds;
+use super::MethodDeclMetadata;
+use crate::parse::method_inputs::MethodArg;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::visit::{self, Visit};
+use syn::{
+    Generics, Ident, Lifetime, Type, TypeImplTrait, TypeInfer, TypeMacro, TypePath, TypeReference,
+    TypeTraitObject, Visibility,
+};
+
+/// Generates the struct holding the owned arguments of a call that are recorded by the mock.
+/// Arguments that can't be stored independently of the call are omitted, see [`is_recordable`].
+pub(crate) fn generate_recorded_arguments(
+    method: &MethodDeclMetadata,
+    visibility: &Visibility,
+    thread_safe: bool,
+) -> TokenStream {
+    let recorded_arguments_ident = recorded_arguments_ident(&method.method_decl.ident);
+    let recorded_arguments = recorded_arguments(method);
+
+    let fields: TokenStream = recorded_arguments
+        .iter()
+        .map(|argument| {
+            let ident = &argument.ident;
+            let ty = &argument.ty;
+            let erased_type = erased_type(ty, thread_safe);
+            quote! { #visibility #ident: mockiato::Recorded<#ty, #erased_type>, }
+        })
+        .collect();
+
+    let field_idents: Vec<_> = recorded_arguments
+        .iter()
+        .map(|argument| &argument.ident)
+        .collect();
+
+    let debug_impl = generate_debug_impl(
+        recorded_arguments.iter().map(|argument| {
+            let ident = &argument.ident;
+            DebugImplField {
+                ident,
+                expression: quote! { mockiato::internal::MaybeDebugWrapper(&self.#ident) },
+            }
+        }),
+        &recorded_arguments_ident,
+        &Generics::default(),
+    );
+
+    quote! {
+        #[doc(hidden)]
+        #visibility struct #recorded_arguments_ident {
+            #fields
+        }
+
+        impl std::clone::Clone for #recorded_arguments_ident {
+            fn clone(&self) -> Self {
+                Self {
+                    #( #field_idents: self.#field_idents.clone(), )*
+                }
+            }
+        }
+
+        #debug_impl
+    }
+}
+
+/// Generates the implementation of `ArgumentsMatcher::record_arguments`,
+/// which records each argument using autoref-based specialization.
+pub(crate) fn generate_record_arguments_method(
+    method: &MethodDeclMetadata,
+    thread_safe: bool,
+) -> TokenStream {
+    let recorded_arguments_ident = recorded_arguments_ident(&method.method_decl.ident);
+
+    let fields: TokenStream = recorded_arguments(method)
+        .iter()
+        .map(|argument| {
+            let ident = &argument.ident;
+            let ty = &argument.ty;
+            let erased_type = erased_type(ty, thread_safe);
+            let recorder = if argument.by_reference {
+                quote! { mockiato::internal::RecordReference::<#ty, #erased_type>::new(&*args.#ident) }
+            } else {
+                quote! { mockiato::internal::RecordValue::<#ty, #erased_type>::new(&args.#ident) }
+            };
+            quote! { #ident: (&#recorder).record(), }
+        })
+        .collect();
+
+    quote! {
+        #[allow(unused_variables)]
+        fn record_arguments(args: &Self::Arguments) -> Self::RecordedArguments {
+            #[allow(unused_imports)]
+            use mockiato::internal::{
+                RecordClonedValue as _, RecordNothing as _, RecordOwnedReference as _,
+            };
+
+            #recorded_arguments_ident {
+                #fields
+            }
+        }
+    }
+}
+
+struct RecordedArgument<'a> {
+    ident: &'a Ident,
+    /// The recorded type, which is the referenced type for arguments passed by reference
+    ty: Type,
+    by_reference: bool,
+}
+
+fn recorded_arguments(method: &MethodDeclMetadata) -> Vec<RecordedArgument<'_>> {
+    let type_params: HashSet<_> = method
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+
+    method
+        .method_decl
+        .inputs
+        .args
+        .iter()
+        .filter_map(|argument| recorded_argument(argument, &type_params))
+        .collect()
+}
+
+fn recorded_argument<'a>(
+    argument: &'a MethodArg,
+    type_params: &HashSet<&Ident>,
+) -> Option<RecordedArgument<'a>> {
+    let (ty, by_reference) = match &argument.ty {
+        Type::Reference(TypeReference { elem, .. }) => (elem.as_ref(), true),
+        ty => (ty, false),
+    };
+
+    if is_recordable(ty, type_params) {
+        Some(RecordedArgument {
+            ident: &argument.ident,
+            ty: ty.clone(),
+            by_reference,
+        })
+    } else {
+        None
+    }
+}
+
+/// The recorded arguments outlive the call, so only types without lifetimes can be recorded.
+/// Trait objects and `impl Trait` are excluded, as their lifetimes are implicit.
+/// Types using type parameters of the trait or the method are excluded as well,
+/// since whether they can be cloned is not known when the mock is generated.
+fn is_recordable(ty: &Type, type_params: &HashSet<&Ident>) -> bool {
+    let mut visitor = RecordableTypeVisitor {
+        type_params,
+        is_recordable: true,
+    };
+    visitor.visit_type(ty);
+    visitor.is_recordable
+}
+
+struct RecordableTypeVisitor<'a> {
+    type_params: &'a HashSet<&'a Ident>,
+    is_recordable: bool,
+}
+
+impl<'a, 'ast> Visit<'ast> for RecordableTypeVisitor<'a> {
+    fn visit_lifetime(&mut self, _lifetime: &'ast Lifetime) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_reference(&mut self, _type_reference: &'ast TypeReference) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_trait_object(&mut self, _type_trait_object: &'ast TypeTraitObject) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_impl_trait(&mut self, _type_impl_trait: &'ast TypeImplTrait) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_macro(&mut self, _type_macro: &'ast TypeMacro) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_infer(&mut self, _type_infer: &'ast TypeInfer) {
+        self.is_recordable = false;
+    }
+
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        let first_segment = type_path
+            .path
+            .segments
+            .first()
+            .map(|segment| &segment.ident);
+        let uses_type_param = matches!(
+            first_segment,
+            Some(ident) if ident == "Self" || self.type_params.contains(ident)
+        );
+
+        if type_path.qself.is_some() || uses_type_param {
+            self.is_recordable = false;
+        }
+
+        visit::visit_type_path(self, type_path);
+    }
+}
+
+/// The type-erased value stored by a `Recorded` argument
+fn erased_type(ty: &Type, thread_safe: bool) -> TokenStream {
+    let thread_safety_bounds = thread_safety_bounds(thread_safe);
+    quote! { dyn std::borrow::Borrow<#ty> #thread_safety_bounds }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    fn is_recordable_with_type_param(ty: Type) -> bool {
+        let type_param: Ident = parse_quote!(V);
+        let type_params = vec![&type_param].into_iter().collect();
+        is_recordable(&ty, &type_params)
+    }
+
+    #[test]
+    fn owned_types_are_recordable() {
+        assert!(is_recordable_with_type_param(parse_quote!(u32)));
+        assert!(is_recordable_with_type_param(parse_quote!(Vec<String>)));
+    }
+
+    #[test]
+    fn types_with_lifetimes_are_not_recordable() {
+        assert!(!is_recordable_with_type_param(parse_quote!(
+            Cow<'static, str>
+        )));
+        assert!(!is_recordable_with_type_param(parse_quote!(Vec<&str>)));
+        assert!(!is_recordable_with_type_param(parse_quote!(Box<dyn Fn()>)));
+    }
+
+    #[test]
+    fn types_using_type_parameters_are_not_recordable() {
+        assert!(!is_recordable_with_type_param(parse_quote!(V)));
+        assert!(!is_recordable_with_type_param(parse_quote!(Vec<V>)));
+        assert!(!is_recordable_with_type_param(parse_quote!(
+            <V as Iterator>::Item
+        )));
+    }
+}
@@ -0,0 +1,175 @@
+use super::debug_impl::generate_debug_impl;
+use super::mock_struct::{
+    debug_impl_field, generate_expect_method, generate_expect_method_calls_in_order_method,
+    generate_initializer_field, generate_method_field,
+};
+use super::util::{doc_attribute, phantom_data_type};
+use super::{GenerateMockParameters, MethodDeclMetadata};
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit_mut::{visit_type_mut, VisitMut};
+use syn::{parse_quote, ReturnType, Type, TypePath};
+
+/// Generates the struct holding the expected calls to the methods of the trait that don't take `self`.
+/// Since these methods can't access the mock, the expectations are registered per thread.
+pub(crate) fn generate_statics_struct(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let methods: Vec<_> = static_methods(parameters).collect();
+
+    if methods.is_empty() {
+        return TokenStream::new();
+    }
+
+    let statics_struct_ident = &parameters.statics_struct_ident;
+    let mock_struct_ident = &parameters.mock_struct_ident;
+
+    let method_fields: TokenStream = methods
+        .iter()
+        .map(|method| generate_method_field(method, trait_decl, parameters))
+        .collect();
+
+    let initializer_fields: TokenStream = methods
+        .iter()
+        .map(|method| generate_initializer_field(method, mock_struct_ident))
+        .collect();
+
+    let expect_methods: TokenStream = methods
+        .iter()
+        .map(|method| generate_expect_method(method, trait_decl, parameters))
+        .collect();
+
+    let expect_method_calls_in_order_methods: TokenStream = methods
+        .iter()
+        .map(|method| generate_expect_method_calls_in_order_method(trait_decl, &method.method_decl))
+        .collect();
+
+    let verify_calls: TokenStream = methods
+        .iter()
+        .map(|method| {
+            let ident = &method.method_decl.ident;
+            quote! { self.#ident.verify_unwrap(); }
+        })
+        .collect();
+
+    let debug_impl_fields = methods
+        .iter()
+        .map(|method| debug_impl_field(&method.method_decl));
+    let debug_impl = generate_debug_impl(
+        debug_impl_fields,
+        statics_struct_ident,
+        &parameters.generics,
+    );
+
+    let visibility = &trait_decl.visibility;
+    let documentation = doc_attribute(format!(
+        "Expected calls to the methods of [`{0}`] that don't take `self`.
+
+The expectations need to be registered using [`{1}::register`]
+before [`{2}`] is used.
+
+[`{0}`]: ./trait.{0}.html
+[`{1}::register`]: ./struct.{1}.html#method.register
+[`{2}`]: ./struct.{2}.html",
+        trait_decl.ident, statics_struct_ident, mock_struct_ident
+    ));
+
+    let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
+
+    let mut static_generics = parameters.generics.clone();
+    static_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: 'static));
+    let (_, _, static_where_clause) = static_generics.split_for_impl();
+
+    let phantom_data_type = phantom_data_type(&trait_decl.generics);
+
+    quote! {
+        #documentation
+        #visibility struct #statics_struct_ident #ty_generics #where_clause {
+            #method_fields
+            phantom_data: #phantom_data_type,
+        }
+
+        impl #impl_generics #statics_struct_ident #ty_generics #where_clause {
+            /// Creates new static expectations with no expected calls.
+            #visibility fn new() -> Self {
+                Self {
+                    #initializer_fields
+                    phantom_data: std::marker::PhantomData,
+                }
+            }
+
+            /// Registers the expectations on the current thread.
+            /// The expected calls are verified once the returned guard goes out of scope.
+            ///
+            /// # Panics
+            /// Panics if expectations for this mock are already registered on the current thread.
+            #visibility fn register(self) -> mockiato::StaticExpectationsGuard<Self>
+            where
+                Self: 'static,
+            {
+                mockiato::StaticExpectationsGuard::internal_register(self)
+            }
+
+            #expect_methods
+
+            #expect_method_calls_in_order_methods
+        }
+
+        impl #impl_generics mockiato::internal::StaticExpectations for #statics_struct_ident #ty_generics #static_where_clause {
+            fn verify_unwrap(&self) {
+                #verify_calls
+            }
+        }
+
+        #debug_impl
+
+        impl #impl_generics Default for #statics_struct_ident #ty_generics #where_clause {
+            /// Creates new static expectations with no expected calls.
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    }
+}
+
+pub(super) fn static_methods(
+    parameters: &GenerateMockParameters,
+) -> impl Iterator<Item = &MethodDeclMetadata> {
+    parameters
+        .methods
+        .iter()
+        .filter(|method| !method.method_decl.has_receiver())
+}
+
+/// Replaces `Self` in the return type of a method without `self` with the type of the mock.
+/// The generated structs live outside of the trait impl, where `Self` is not available.
+pub(super) fn resolve_self_type(method_decl: &mut MethodDecl, mock_type: &Type) {
+    if method_decl.has_receiver() {
+        return;
+    }
+
+    if let ReturnType::Type(_, ty) = &mut method_decl.output {
+        SelfTypeResolver { mock_type }.visit_type_mut(ty);
+    }
+}
+
+struct SelfTypeResolver<'a> {
+    mock_type: &'a Type,
+}
+
+impl<'a> VisitMut for SelfTypeResolver<'a> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self") => {
+                *ty = self.mock_type.clone()
+            }
+            _ => visit_type_mut(self, ty),
+        }
+    }
+}
@@ -1,11 +1,17 @@
+use super::associated_consts::generate_associated_consts_for_trait_impl;
+use super::associated_types::generate_associated_types;
+use super::future::FutureKind;
+use super::method_types::type_params_key;
+use super::statics::static_methods;
+use super::util::static_mock_predicates;
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::trait_decl::TraitDecl;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::punctuated::Punctuated;
-use syn::{Ident, Token};
+use syn::{Generics, LitStr, Token};
 
 pub(crate) fn generate_trait_impl(
     trait_decl: &TraitDecl,
@@ -18,25 +24,57 @@ pub(crate) fn generate_trait_impl(
     let method_impls: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_method_impl(method, &parameters.mod_ident))
+        .map(|method| generate_method_impl(method, parameters))
         .collect();
 
-    let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
+    let associated_types = generate_associated_types(&parameters.associated_types);
+    let associated_consts =
+        generate_associated_consts_for_trait_impl(&parameters.associated_consts);
+
+    let generics = trait_impl_generics(trait_decl, parameters);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
 
     quote! {
         #unsafety impl #impl_generics #trait_path #trait_ty_generics for #mock_struct_ident #ty_generics #where_clause {
+            #associated_types
+            #associated_consts
             #method_impls
         }
     }
 }
 
+/// Expectations for methods without `self` are stored in a thread-local registry
+/// and the expectations of generic methods are downcast using `Any`,
+/// which requires the mock to be `'static` if the trait contains such methods.
+fn trait_impl_generics(trait_decl: &TraitDecl, parameters: &GenerateMockParameters) -> Generics {
+    let mut generics = parameters.generics.clone();
+
+    let has_static_methods = static_methods(parameters).next().is_some();
+    let has_generic_methods = parameters
+        .methods
+        .iter()
+        .any(|method| !method.type_params.is_empty());
+
+    if has_static_methods || has_generic_methods {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(static_mock_predicates(&trait_decl.generics));
+    }
+
+    generics
+}
+
 fn generate_method_impl(
     MethodDeclMetadata {
         arguments_struct_ident,
+        future_kind,
+        type_params,
         method_decl:
             MethodDecl {
                 ident,
+                asyncness,
                 unsafety,
                 generics,
                 inputs,
@@ -45,9 +83,13 @@ fn generate_method_impl(
             },
         ..
     }: &MethodDeclMetadata,
-    mod_ident: &Ident,
+    parameters: &GenerateMockParameters,
 ) -> TokenStream {
-    let self_arg = &inputs.self_arg;
+    let mod_ident = &parameters.mod_ident;
+    let self_arg = inputs
+        .self_arg
+        .as_ref()
+        .map(|self_arg| quote! { #self_arg, });
     let arguments: Punctuated<_, Token![,]> = inputs.args.iter().collect();
 
     let (impl_generics, _, where_clause) = generics.split_for_impl();
@@ -61,14 +103,51 @@ fn generate_method_impl(
         })
         .collect();
 
-    quote! {
-        #unsafety fn #ident#impl_generics(#self_arg, #arguments) #output #where_clause {
-            self.#ident.call_unwrap(
-                #mod_ident::#arguments_struct_ident {
-                    #arguments_struct_fields
-                    phantom_data: std::marker::PhantomData,
-                }
+    let call_unwrap_turbofish = if type_params.is_empty() {
+        TokenStream::new()
+    } else {
+        let type_params_key = type_params_key(type_params);
+        quote! { ::<#type_params_key> }
+    };
+
+    let arguments_struct = quote! {
+        #mod_ident::#arguments_struct_ident {
+            #arguments_struct_fields
+            phantom_data: std::marker::PhantomData,
+        }
+    };
+
+    let call = if inputs.self_arg.is_some() {
+        quote! {
+            self.#ident.call_unwrap#call_unwrap_turbofish(#arguments_struct)
+        }
+    } else {
+        let statics_struct_ident = &parameters.statics_struct_ident;
+        let (_, ty_generics, _) = parameters.generics.split_for_impl();
+        let name = LitStr::new(
+            &format!("{}::{}", parameters.mock_struct_ident, ident),
+            Span::call_site(),
+        );
+        quote! {
+            mockiato::internal::with_static_expectations(
+                #name,
+                move |statics: &#statics_struct_ident #ty_generics| {
+                    statics.#ident.call_unwrap#call_unwrap_turbofish(#arguments_struct)
+                },
             )
         }
+    };
+
+    let body = match future_kind {
+        Some(FutureKind::BoxedFuture) => quote! {
+            std::boxed::Box::pin(async move { #call })
+        },
+        Some(FutureKind::AsyncFn) | None => call,
+    };
+
+    quote! {
+        #asyncness #unsafety fn #ident#impl_generics(#self_arg #arguments) #output #where_clause {
+            #body
+        }
     }
 }
@@ -1,5 +1,11 @@
-use proc_macro2::Span;
-use syn::{parse_quote, Attribute, GenericParam, Ident, Lifetime, LifetimeDef, LitStr};
+use super::constant::mock_lifetime;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, Attribute, GenericParam, Generics, Ident, Lifetime, LifetimeDef, LitStr,
+    WherePredicate,
+};
 
 pub(super) fn doc_attribute(content: String) -> Attribute {
     let string_literal = LitStr::new(&content, Span::call_site());
@@ -13,6 +19,51 @@ pub(super) fn ident_to_string_literal(ident: &Ident) -> LitStr {
     LitStr::new(&ident.to_string(), ident.span())
 }
 
+/// Additional bounds for the trait objects stored by a mock,
+/// which need to be [`Send`] and [`Sync`] for thread-safe mocks.
+pub(super) fn thread_safety_bounds(thread_safe: bool) -> TokenStream {
+    if thread_safe {
+        quote! { + std::marker::Send + std::marker::Sync }
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Methods of the mock shadow methods of the trait with the same name,
+/// which is why they are omitted if the trait already declares such a method.
+pub(super) fn trait_has_method(trait_decl: &TraitDecl, name: &str) -> bool {
+    trait_decl
+        .methods
+        .iter()
+        .any(|method_decl| method_decl.ident == name)
+}
+
 pub(super) fn lifetime_to_generic_param(lifetime: Lifetime) -> GenericParam {
     GenericParam::Lifetime(LifetimeDef::new(lifetime))
 }
+
+/// The type of the `phantom_data` field of the generated structs.
+/// It uses the mock lifetime and the type parameters of the trait,
+/// which are not necessarily used by the fields for the trait's methods.
+pub(super) fn phantom_data_type(trait_generics: &Generics) -> TokenStream {
+    let mock_lifetime = mock_lifetime();
+    let type_params = trait_generics.type_params().map(|param| &param.ident);
+
+    quote! {
+        std::marker::PhantomData<fn() -> (&#mock_lifetime (), #(std::marker::PhantomData<#type_params>,)*)>
+    }
+}
+
+/// Predicates requiring the mock to be `'static`, which is the case if the trait
+/// contains methods without `self` or generic methods.
+pub(super) fn static_mock_predicates(trait_generics: &Generics) -> Vec<WherePredicate> {
+    let mock_lifetime = mock_lifetime();
+    let type_params = trait_generics.type_params().map(|param| -> WherePredicate {
+        let ident = &param.ident;
+        parse_quote!(#ident: 'static)
+    });
+
+    std::iter::once(parse_quote!(#mock_lifetime: 'static))
+        .chain(type_params)
+        .collect()
+}
@@ -0,0 +1,274 @@
+use super::constant::mock_lifetime;
+use super::GenerateMockParameters;
+use super::MethodDeclMetadata;
+use crate::code_generator_impl::util::doc_attribute;
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::method_inputs::MethodSelfArg;
+use crate::parse::mockable_attr::AssociatedType;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit::{visit_type, visit_type_reference, Visit};
+use syn::{GenericParam, Lifetime, ReturnType, Type, TypeReference};
+
+/// Generates `with_fallback`, which creates a partial mock that forwards calls to a real
+/// implementation of the trait.
+pub(super) fn generate_with_fallback_method(
+    trait_decl: &TraitDecl,
+    methods: &[&MethodDeclMetadata],
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let documentation = doc_attribute(format!(
+        "Creates a new mock that forwards calls to `fallback`, a real implementation of [`{0}`],
+if they don't match any expected call or if the expected call was configured using `calls_through`.
+
+Generic methods, asynchronous methods and methods returning borrowed data
+can't call through to `fallback`.
+
+[`{0}`]: ./trait.{0}.html",
+        trait_decl.ident
+    ));
+
+    let visibility = &trait_decl.visibility;
+    let trait_bound = trait_bound(trait_decl, parameters);
+    let mock_lifetime = mock_lifetime();
+    let send_bound = if parameters.thread_safe {
+        quote! { + std::marker::Send }
+    } else {
+        TokenStream::new()
+    };
+
+    let set_real_implementations: TokenStream = methods
+        .iter()
+        .filter(|method| can_call_through(method))
+        .map(|method| generate_set_real_implementation(method, parameters))
+        .collect();
+
+    quote! {
+        #documentation
+        #[allow(unused_variables)]
+        #visibility fn with_fallback(fallback: impl #trait_bound #send_bound + #mock_lifetime) -> Self {
+            let fallback = std::sync::Arc::new(std::sync::Mutex::new(fallback));
+            let mut mock = Self::new();
+            #set_real_implementations
+            mock
+        }
+    }
+}
+
+/// The trait including its generic arguments and the types of its associated types,
+/// e.g. `Source<T, Item = u32>`
+fn trait_bound(trait_decl: &TraitDecl, parameters: &GenerateMockParameters) -> TokenStream {
+    let trait_path = &parameters.trait_path;
+
+    let generic_arguments = trait_decl.generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(lifetime_def) => {
+            let lifetime = &lifetime_def.lifetime;
+            quote! { #lifetime }
+        }
+        GenericParam::Type(type_param) => {
+            let ident = &type_param.ident;
+            quote! { #ident }
+        }
+        GenericParam::Const(const_param) => {
+            let ident = &const_param.ident;
+            quote! { #ident }
+        }
+    });
+
+    let associated_types = parameters
+        .associated_types
+        .iter()
+        .map(|AssociatedType { ident, ty }| quote! { #ident = #ty });
+
+    let arguments: Vec<_> = generic_arguments.chain(associated_types).collect();
+
+    if arguments.is_empty() {
+        quote! { #trait_path }
+    } else {
+        quote! { #trait_path<#(#arguments),*> }
+    }
+}
+
+fn generate_set_real_implementation(
+    MethodDeclMetadata {
+        method_decl:
+            MethodDecl {
+                ident,
+                unsafety,
+                inputs,
+                ..
+            },
+        ..
+    }: &MethodDeclMetadata,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let trait_path = &parameters.trait_path;
+
+    let is_mutable_receiver = match inputs.self_arg {
+        Some(MethodSelfArg::Ref(ref receiver)) => receiver.mutability.is_some(),
+        _ => false,
+    };
+    let (binding, receiver) = if is_mutable_receiver {
+        (quote! { mut fallback }, quote! { &mut *fallback })
+    } else {
+        (quote! { fallback }, quote! { &*fallback })
+    };
+
+    let arguments = inputs.args.iter().map(|argument| {
+        let ident = &argument.ident;
+        quote! { arguments.#ident }
+    });
+
+    let call = quote! { #trait_path::#ident(#receiver, #(#arguments),*) };
+    let call = match unsafety {
+        Some(_) => quote! { unsafe { #call } },
+        None => call,
+    };
+
+    quote! {
+        {
+            let fallback = std::sync::Arc::clone(&fallback);
+            mock.#ident.set_real_implementation(move |arguments| {
+                let #binding = fallback.lock().unwrap_or_else(|error| error.into_inner());
+                #call
+            });
+        }
+    }
+}
+
+/// Calls can only be forwarded by a closure that works for all arguments and returns
+/// an owned value, which rules out generic methods, asynchronous methods and methods
+/// whose return value borrows from `self` or the arguments.
+fn can_call_through(method: &MethodDeclMetadata) -> bool {
+    let MethodDeclMetadata {
+        method_decl,
+        type_params,
+        future_kind,
+        ..
+    } = method;
+
+    // The real implementation is only borrowed while it is called,
+    // so the borrow of `self` must not be tied to the arguments.
+    let takes_self_by_reference = match method_decl.inputs.self_arg {
+        Some(MethodSelfArg::Ref(ref receiver)) => match receiver.reference {
+            Some((_, Some(ref lifetime))) => lifetime.ident == "_",
+            _ => true,
+        },
+        _ => false,
+    };
+
+    takes_self_by_reference
+        && type_params.is_empty()
+        && future_kind.is_none()
+        && arguments_can_be_forwarded(method_decl)
+        && return_type_can_be_forwarded(&method_decl.output)
+}
+
+/// The lifetimes of the arguments are rewritten when they are stored, which only works
+/// for the real implementation if they are elided or declared on the method without bounds.
+fn arguments_can_be_forwarded(method_decl: &MethodDecl) -> bool {
+    let generics = &method_decl.generics;
+    let has_lifetime_bounds = generics
+        .lifetimes()
+        .any(|lifetime_def| !lifetime_def.bounds.is_empty())
+        || matches!(
+            &generics.where_clause,
+            Some(where_clause) if !where_clause.predicates.is_empty()
+        );
+
+    if has_lifetime_bounds {
+        return false;
+    }
+
+    let method_lifetimes: Vec<_> = generics
+        .lifetimes()
+        .map(|lifetime_def| &lifetime_def.lifetime)
+        .collect();
+
+    method_decl.inputs.args.iter().all(|argument| {
+        let signature = TypeSignature::of(&argument.ty);
+        !signature.mentions_self
+            && signature
+                .lifetimes
+                .iter()
+                .all(|lifetime| lifetime.ident == "_" || method_lifetimes.contains(lifetime))
+    })
+}
+
+fn return_type_can_be_forwarded(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ty) => {
+            let signature = TypeSignature::of(ty);
+            !signature.mentions_self
+                && !signature.has_elided_references
+                && signature
+                    .lifetimes
+                    .iter()
+                    .all(|lifetime| lifetime.ident == "static")
+        }
+    }
+}
+
+#[derive(Default)]
+struct TypeSignature<'a> {
+    lifetimes: Vec<&'a Lifetime>,
+    has_elided_references: bool,
+    mentions_self: bool,
+}
+
+impl<'a> TypeSignature<'a> {
+    fn of(ty: &'a Type) -> Self {
+        let mut signature = Self::default();
+        signature.visit_type(ty);
+        signature
+    }
+}
+
+impl<'a> Visit<'a> for TypeSignature<'a> {
+    fn visit_lifetime(&mut self, lifetime: &'a Lifetime) {
+        self.lifetimes.push(lifetime);
+    }
+
+    fn visit_type_reference(&mut self, type_reference: &'a TypeReference) {
+        if type_reference.lifetime.is_none() {
+            self.has_elided_references = true;
+        }
+
+        visit_type_reference(self, type_reference);
+    }
+
+    fn visit_type(&mut self, ty: &'a Type) {
+        match ty {
+            Type::Path(type_path)
+                if type_path.qself.is_none() && type_path.path.is_ident("Self") =>
+            {
+                self.mentions_self = true
+            }
+            _ => visit_type(self, ty),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn owned_return_type_can_be_forwarded() {
+        assert!(return_type_can_be_forwarded(&parse_quote!(-> Vec<String>)));
+        assert!(return_type_can_be_forwarded(&parse_quote!(-> &'static str)));
+        assert!(return_type_can_be_forwarded(&ReturnType::Default));
+    }
+
+    #[test]
+    fn borrowed_return_type_can_not_be_forwarded() {
+        assert!(!return_type_can_be_forwarded(&parse_quote!(-> &str)));
+        assert!(!return_type_can_be_forwarded(
+            &parse_quote!(-> Cow<'a, str>)
+        ));
+        assert!(!return_type_can_be_forwarded(&parse_quote!(-> Self)));
+    }
+}
@@ -2,4 +2,11 @@ pub(crate) const ATTR_NAME: &str = "mockable";
 pub(crate) const STATIC_REFERENCES_ATTR_PARAM_NAME: &str = "static_references";
 pub(crate) const MOCK_STRUCT_NAME_ATTR_PARAM_NAME: &str = "name";
 pub(crate) const REMOTE_ATTR_PARAM_NAME: &str = "remote";
+pub(crate) const SYNC_ATTR_PARAM_NAME: &str = "sync";
+pub(crate) const OBJECT_SAFE_ATTR_PARAM_NAME: &str = "object_safe";
+pub(crate) const LENIENT_ATTR_PARAM_NAME: &str = "lenient";
+pub(crate) const USE_DEFAULT_ATTR_PARAM_NAME: &str = "use_default";
+pub(crate) const ASSOCIATED_TYPE_ATTR_PARAM_NAME: &str = "associated_type";
+pub(crate) const ASSOCIATED_CONST_ATTR_PARAM_NAME: &str = "associated_const";
+pub(crate) const ASYNC_TRAIT_ATTR_PARAM_NAME: &str = "async_trait";
 pub(crate) const CREATE_ISSUE_LINK: &str = "https://github.com/myelin-ai/mockiato/issues/new";
@@ -1,13 +1,17 @@
 use crate::code_generator::{self, CodeGenerator};
+use crate::constant::{
+    ASSOCIATED_CONST_ATTR_PARAM_NAME, ASSOCIATED_TYPE_ATTR_PARAM_NAME, ATTR_NAME,
+    OBJECT_SAFE_ATTR_PARAM_NAME, USE_DEFAULT_ATTR_PARAM_NAME,
+};
 use crate::diagnostic::DiagnosticBuilder;
-use crate::parse::mockable_attr::{MockableAttr, MockableAttrParser, RemoteTraitPath};
-use crate::parse::trait_decl::TraitDeclParser;
+use crate::parse::mockable_attr::{MockableAttr, MockableAttrParser, RemoteTraitPath, UseDefault};
+use crate::parse::trait_decl::{TraitDecl, TraitDeclParser};
 use crate::result::{Error, Result};
 use crate::Controller;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Item, ItemTrait};
+use syn::{AttributeArgs, Ident, Item, ItemTrait};
 
 #[derive(Debug)]
 pub(crate) struct ControllerImpl {
@@ -34,11 +38,22 @@ impl Controller for ControllerImpl {
     fn expand_mockable_trait(&self, attr: AttributeArgs, item: Item) -> Result<TokenStream> {
         let mockable_attr = self.mockable_attr_parser.parse(attr)?;
         let item_trait = extract_item_trait(item)?;
-        let trait_decl = self
+        let mut trait_decl = self
             .trait_decl_parser
             .parse(item_trait.clone())
             .map_err(add_note_to_error)?;
 
+        validate_associated_items(&trait_decl, &mockable_attr)?;
+
+        if let Some(ref use_default) = mockable_attr.use_default {
+            let is_remote = mockable_attr.remote_trait_path.is_some();
+            remove_methods_using_default(&mut trait_decl, use_default, is_remote)?;
+        }
+
+        if mockable_attr.object_safe {
+            validate_object_safety(&trait_decl)?;
+        }
+
         let emit_item_trait = match mockable_attr.remote_trait_path {
             Some(_) => None,
             None => Some(item_trait),
@@ -59,6 +74,12 @@ fn generate_options_from_mockable_attr(
         remote_trait_path,
         name,
         force_static_lifetimes,
+        sync,
+        lenient,
+        associated_types,
+        associated_consts,
+        async_trait,
+        ..
     }: MockableAttr,
 ) -> code_generator::GenerateOptions {
     let custom_trait_path = match remote_trait_path {
@@ -69,9 +90,144 @@ fn generate_options_from_mockable_attr(
         custom_struct_ident: name,
         force_static_lifetimes,
         custom_trait_path,
+        thread_safe: sync,
+        lenient,
+        associated_types,
+        associated_consts,
+        async_trait,
+    }
+}
+
+fn validate_associated_items(trait_decl: &TraitDecl, mockable_attr: &MockableAttr) -> Result<()> {
+    let declared_types: Vec<_> = trait_decl.associated_types.iter().collect();
+    let specified_types: Vec<_> = mockable_attr
+        .associated_types
+        .iter()
+        .map(|associated_type| &associated_type.ident)
+        .collect();
+
+    let declared_consts: Vec<_> = trait_decl
+        .associated_consts
+        .iter()
+        .map(|associated_const| &associated_const.ident)
+        .collect();
+    let required_consts: Vec<_> = trait_decl
+        .associated_consts
+        .iter()
+        .filter(|associated_const| !associated_const.has_default)
+        .map(|associated_const| &associated_const.ident)
+        .collect();
+    let specified_consts: Vec<_> = mockable_attr
+        .associated_consts
+        .iter()
+        .map(|associated_const| &associated_const.ident)
+        .collect();
+
+    let errors: Vec<_> = validate_specified_items(
+        &declared_types,
+        &declared_types,
+        &specified_types,
+        missing_associated_type_error,
+        unknown_associated_type_error,
+    )
+    .chain(validate_specified_items(
+        &declared_consts,
+        &required_consts,
+        &specified_consts,
+        missing_associated_const_error,
+        unknown_associated_const_error,
+    ))
+    .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
+    }
+}
+
+/// Removes the methods whose default implementation should be used,
+/// so that they are neither mocked nor implemented by the mock.
+fn remove_methods_using_default(
+    trait_decl: &mut TraitDecl,
+    use_default: &UseDefault,
+    is_remote: bool,
+) -> Result<()> {
+    match use_default {
+        UseDefault::AllMethods => {
+            trait_decl
+                .methods
+                .retain(|method_decl| !method_decl.has_default);
+            Ok(())
+        }
+        UseDefault::Methods(idents) => {
+            let errors: Vec<_> = idents
+                .iter()
+                .filter_map(|ident| {
+                    let method_decl = trait_decl
+                        .methods
+                        .iter()
+                        .find(|method_decl| method_decl.ident == *ident);
+
+                    match method_decl {
+                        None => Some(unknown_method_error(ident)),
+                        // The default implementations of remote traits are not part of the declaration.
+                        Some(method_decl) if !method_decl.has_default && !is_remote => {
+                            Some(method_without_default_error(ident))
+                        }
+                        Some(_) => None,
+                    }
+                })
+                .collect();
+
+            if !errors.is_empty() {
+                return Err(errors.into_iter().collect());
+            }
+
+            trait_decl
+                .methods
+                .retain(|method_decl| !idents.contains(&method_decl.ident));
+            Ok(())
+        }
+    }
+}
+
+fn validate_object_safety(trait_decl: &TraitDecl) -> Result<()> {
+    let errors: Vec<_> = trait_decl
+        .methods
+        .iter()
+        .filter(|method_decl| !method_decl.has_receiver())
+        .map(|method_decl| first_argument_is_not_self_error(method_decl.span))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
     }
 }
 
+/// Checks that all `required` items are `specified` and that only `declared` items are `specified`.
+fn validate_specified_items<'a>(
+    declared: &'a [&'a Ident],
+    required: &'a [&'a Ident],
+    specified: &'a [&'a Ident],
+    missing_item_error: fn(&Ident) -> Error,
+    unknown_item_error: fn(&Ident) -> Error,
+) -> impl Iterator<Item = Error> + 'a {
+    let missing_items = required
+        .iter()
+        .filter(move |ident| !specified.contains(ident))
+        .map(move |ident| missing_item_error(ident));
+
+    let unknown_items = specified
+        .iter()
+        .filter(move |ident| !declared.contains(ident))
+        .map(move |ident| unknown_item_error(ident));
+
+    missing_items.chain(unknown_items)
+}
+
 fn extract_item_trait(item: Item) -> Result<ItemTrait> {
     match item {
         Item::Trait(item_trait) => Ok(item_trait),
@@ -91,6 +247,86 @@ fn add_note_to_error(error: Error) -> Error {
         .collect()
 }
 
+fn missing_associated_type_error(ident: &Ident) -> Error {
+    let error_message = format!(
+        "The type of the associated type `{}` is not specified",
+        ident
+    );
+    let help_message = format!(
+        "Specify the type using #[{attr}({param}({ident} = \"...\"))]",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_TYPE_ATTR_PARAM_NAME,
+        ident = ident
+    );
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn unknown_associated_type_error(ident: &Ident) -> Error {
+    let error_message = format!("`{}` is not an associated type of this trait", ident);
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
+fn missing_associated_const_error(ident: &Ident) -> Error {
+    let error_message = format!(
+        "The value of the associated constant `{}` is not specified",
+        ident
+    );
+    let help_message = format!(
+        "Specify the value using #[{attr}({param}({ident} = ...))]",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_CONST_ATTR_PARAM_NAME,
+        ident = ident
+    );
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn unknown_associated_const_error(ident: &Ident) -> Error {
+    let error_message = format!("`{}` is not an associated constant of this trait", ident);
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
+fn unknown_method_error(ident: &Ident) -> Error {
+    let error_message = format!("`{}` is not a method of this trait", ident);
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
+fn method_without_default_error(ident: &Ident) -> Error {
+    let error_message = format!("The method `{}` has no default implementation", ident);
+    let note_message = format!(
+        "Required because of #[{}({}(...))]",
+        ATTR_NAME, USE_DEFAULT_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .note(note_message)
+        .build()
+        .into()
+}
+
+fn first_argument_is_not_self_error(span: Span) -> Error {
+    let error_message =
+        "The first parameter of a method must be self, so that the trait is object-safe";
+    let note_message = format!(
+        "Required because of #[{}({})]",
+        ATTR_NAME, OBJECT_SAFE_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .note(note_message)
+        .build()
+        .into()
+}
+
 fn only_traits_can_be_made_mockable_error(item: &Item) -> Error {
     DiagnosticBuilder::error(item.span(), "Only traits can be made mockable")
         .note_with_span(Span::call_site(), "Required because of this attribute")
@@ -8,6 +8,8 @@ pub(crate) enum DiagnosticLevel {
     Error,
 }
 
+// Only the nightly diagnostics emitter reads all fields.
+#[cfg_attr(not(rustc_is_nightly), allow(dead_code))]
 #[derive(Debug)]
 pub(crate) struct Diagnostic {
     pub(crate) span: Span,
@@ -17,6 +19,7 @@ pub(crate) struct Diagnostic {
     pub(crate) help: Vec<DiagnosticMessage>,
 }
 
+#[cfg_attr(not(rustc_is_nightly), allow(dead_code))]
 #[derive(Debug)]
 pub(crate) struct DiagnosticMessage {
     pub(crate) span: Option<Span>,
@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::fmt::Debug;
 
 use proc_macro2::Span;
@@ -13,7 +12,10 @@ use crate::result::Result;
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
 pub(crate) struct MethodDecl {
     /// A list of attributes decorating this method. (E.g. `#[inline(always)]`)
+    #[allow(dead_code)]
     pub(crate) attrs: Vec<Attribute>,
+    /// Whether this method is async or not
+    pub(crate) asyncness: Option<Token![async]>,
     /// Whether this method is unsafe or not
     pub(crate) unsafety: Option<Token![unsafe]>,
     /// The name of this method. (E.g. `greet`)
@@ -26,12 +28,18 @@ pub(crate) struct MethodDecl {
     pub(crate) inputs: MethodInputs,
     /// Return type of this method.
     pub(crate) output: ReturnType,
+    /// Whether the trait provides a default implementation for this method
+    pub(crate) has_default: bool,
+}
+
+impl MethodDecl {
+    /// Whether this method takes `self` in some form.
+    /// Methods without a receiver are mocked through the mock's static expectations.
+    pub(crate) fn has_receiver(&self) -> bool {
+        self.inputs.self_arg.is_some()
+    }
 }
 
 pub(crate) trait MethodDeclParser: Debug {
-    fn parse(
-        &self,
-        trait_item: TraitItem,
-        generic_types_on_trait: &HashSet<Ident>,
-    ) -> Result<MethodDecl>;
+    fn parse(&self, trait_item: TraitItem) -> Result<MethodDecl>;
 }
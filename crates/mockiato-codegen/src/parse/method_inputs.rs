@@ -11,7 +11,8 @@ use crate::result::Result;
 #[derive(Clone)]
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
 pub(crate) struct MethodInputs {
-    pub(crate) self_arg: MethodSelfArg,
+    /// The receiver of the method. `None` for associated functions without `self`.
+    pub(crate) self_arg: Option<MethodSelfArg>,
     pub(crate) args: Vec<MethodArg>,
 }
 
@@ -51,11 +52,16 @@ pub(crate) trait MethodSelfArgParser: Debug {
 pub(crate) struct MethodArg {
     pub(crate) ident: Ident,
     pub(crate) ty: Type,
+    #[allow(dead_code)]
     pub(crate) span: Span,
 }
 
 pub(crate) trait MethodArgParser: Debug {
-    fn parse(&self, arg: FnArg) -> Result<MethodArg>;
+    /// Parses an argument of a method.
+    /// `index` is the position of the argument, not counting `self`.
+    /// `named_args` are the identifiers of all arguments that have a name,
+    /// which are avoided when synthesizing names for arguments without one.
+    fn parse(&self, arg: FnArg, index: usize, named_args: &[Ident]) -> Result<MethodArg>;
 }
 
 impl ToTokens for MethodArg {
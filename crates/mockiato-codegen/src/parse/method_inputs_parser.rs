@@ -9,7 +9,7 @@ use crate::parse::method_inputs::{
     MethodArg, MethodArgParser, MethodInputs, MethodInputsParser, MethodSelfArg,
     MethodSelfArgParser,
 };
-use crate::result::{merge_results, Error, Result};
+use crate::result::{merge_results, Result};
 
 #[derive(Debug)]
 pub(crate) struct MethodInputsParserImpl {
@@ -31,28 +31,38 @@ impl MethodInputsParserImpl {
 
 impl MethodInputsParser for MethodInputsParserImpl {
     fn parse(&self, inputs: Punctuated<FnArg, Token![,]>) -> Result<MethodInputs> {
-        let span = inputs.span();
-        let mut inputs_iter = inputs.into_iter();
+        let mut inputs_iter = inputs.into_iter().peekable();
 
         let self_arg = inputs_iter
-            .next()
-            .ok_or(())
-            .and_then(|arg| self.method_self_arg_parser.parse(arg))
-            .map_err(|_| first_argument_is_not_self_error(span))?;
+            .peek()
+            .and_then(|arg| self.method_self_arg_parser.parse(arg.clone()).ok());
 
-        let args = inputs_iter.map(|arg| self.method_arg_parser.parse(arg));
+        if self_arg.is_some() {
+            inputs_iter.next();
+        }
+
+        let inputs: Vec<_> = inputs_iter.collect();
+        let named_args: Vec<_> = inputs.iter().filter_map(named_arg_ident).collect();
+
+        let args = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| self.method_arg_parser.parse(arg, index, &named_args));
+        let args = merge_results(args)?.collect();
 
-        Ok(MethodInputs {
-            self_arg,
-            args: merge_results(args)?.collect(),
-        })
+        Ok(MethodInputs { self_arg, args })
     }
 }
 
-fn first_argument_is_not_self_error(span: Span) -> Error {
-    let error_message =
-        "The first parameter of a method must be self, so that the trait is object-safe";
-    DiagnosticBuilder::error(span, error_message).build().into()
+/// Returns the sanitized identifier of an argument that has a name
+fn named_arg_ident(arg: &FnArg) -> Option<Ident> {
+    match arg {
+        FnArg::Typed(PatType { pat, .. }) => match **pat {
+            Pat::Ident(PatIdent { ref ident, .. }) => Some(sanitize_method_ident(ident)),
+            _ => None,
+        },
+        FnArg::Receiver(_) => None,
+    }
 }
 
 #[derive(Debug)]
@@ -100,7 +110,7 @@ impl MethodArgParserImpl {
 }
 
 impl MethodArgParser for MethodArgParserImpl {
-    fn parse(&self, arg: FnArg) -> Result<MethodArg> {
+    fn parse(&self, arg: FnArg, index: usize, named_args: &[Ident]) -> Result<MethodArg> {
         let span = arg.span();
 
         match arg {
@@ -124,11 +134,13 @@ impl MethodArgParser for MethodArgParserImpl {
                             span,
                         })
                     }
-                    _ => Err(
-                        DiagnosticBuilder::error(span, "Ignored arguments are not supported")
-                            .build()
-                            .into(),
-                    ),
+                    // Wildcards (`_`) and destructuring patterns don't have a name
+                    // that could be used in the generated structs.
+                    _ => Ok(MethodArg {
+                        ident: synthesized_argument_ident(index, named_args, span),
+                        ty: *captured.ty,
+                        span,
+                    }),
                 }
             }
             _ => Err(
@@ -145,6 +157,20 @@ impl MethodArgParser for MethodArgParserImpl {
     }
 }
 
+/// Generates a stable name for an argument without an identifier.
+/// Underscores are appended until the name differs from the names of all `named_args`.
+fn synthesized_argument_ident(index: usize, named_args: &[Ident], span: Span) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "arg";
+
+    let mut ident = format!("{}{}", IDENTIFIER_PREFIX, index);
+
+    while named_args.iter().any(|named_arg| *named_arg == ident) {
+        ident.push('_');
+    }
+
+    Ident::new(&ident, span)
+}
+
 /// Sanitizes a method identifier by removing all leading underscores
 fn sanitize_method_ident(ident: &Ident) -> Ident {
     let ident_string = ident.to_string();
@@ -152,3 +178,38 @@ fn sanitize_method_ident(ident: &Ident) -> Ident {
 
     Ident::new(sanitized_ident_str, ident.span())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    fn arg_idents(inputs: Punctuated<FnArg, Token![,]>) -> Vec<String> {
+        let parser = MethodInputsParserImpl::new(
+            Box::new(MethodSelfArgParserImpl::new()),
+            Box::new(MethodArgParserImpl::new()),
+        );
+
+        parser
+            .parse(inputs)
+            .unwrap()
+            .args
+            .iter()
+            .map(|arg| arg.ident.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn wildcard_arguments_are_named_after_their_position() {
+        let inputs = parse_quote!(&self, _: u8, height: u32, _: u8);
+
+        assert_eq!(vec!["arg0", "height", "arg2"], arg_idents(inputs));
+    }
+
+    #[test]
+    fn synthesized_names_do_not_collide_with_named_arguments() {
+        let inputs = parse_quote!(&self, _: u8, arg0: u8, _arg0_: u8);
+
+        assert_eq!(vec!["arg0__", "arg0", "arg0_"], arg_idents(inputs));
+    }
+}
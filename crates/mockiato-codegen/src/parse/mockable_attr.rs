@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use syn::{AttributeArgs, Ident, Path};
+use syn::{AttributeArgs, Ident, Lit, Path, Type};
 
 use crate::result::Result;
 
@@ -17,6 +17,43 @@ pub(crate) struct MockableAttr {
     /// Enables mocking of a remote trait.
     /// Example usage: `#[mockable(remote = "io::Write")]`
     pub(crate) remote_trait_path: Option<RemoteTraitPath>,
+    /// Makes the generated mock usable across threads.
+    /// Example usage: `#[mockable(sync)]`
+    pub(crate) sync: bool,
+    /// Requires all methods to take `self`, so that the trait is object-safe.
+    /// Example usage: `#[mockable(object_safe)]`
+    pub(crate) object_safe: bool,
+    /// Allows unexpected calls, which return a fallback value instead of panicking.
+    /// Example usage: `#[mockable(lenient)]`
+    pub(crate) lenient: bool,
+    /// Uses the trait's default implementation instead of mocking the method.
+    /// Example usage: `#[mockable(use_default)]` or `#[mockable(use_default(greet))]`
+    pub(crate) use_default: Option<UseDefault>,
+    /// Specifies the types of the trait's associated types.
+    /// Example usage: `#[mockable(associated_type(Item = "u32"))]`
+    pub(crate) associated_types: Vec<AssociatedType>,
+    /// Specifies the values of the trait's associated constants.
+    /// Example usage: `#[mockable(associated_const(NAME = "foo", VERSION = 3))]`
+    pub(crate) associated_consts: Vec<AssociatedConst>,
+    /// Mocks methods returning `Pin<Box<dyn Future<Output = T>>>` as if they were `async fn`s.
+    /// Example usage: `#[mockable(async_trait)]`
+    pub(crate) async_trait: bool,
+}
+
+/// An associated type of the mocked trait and the type it is set to.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedType {
+    pub(crate) ident: Ident,
+    pub(crate) ty: Type,
+}
+
+/// An associated constant of the mocked trait and the value it is set to.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedConst {
+    pub(crate) ident: Ident,
+    pub(crate) value: Lit,
 }
 
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -29,6 +66,16 @@ pub(crate) enum RemoteTraitPath {
     Path(Path),
 }
 
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) enum UseDefault {
+    /// Corresponds to the `use_default` parameter without a value:  
+    /// `#[mockable(use_default)]`
+    AllMethods,
+    /// Corresponds to the `use_default` parameter with a list of methods:  
+    /// `#[mockable(use_default(greet, leave))]`
+    Methods(Vec<Ident>),
+}
+
 #[cfg_attr(test, mockiato::mockable)]
 pub(crate) trait MockableAttrParser: Debug {
     fn parse(&self, args: AttributeArgs) -> Result<MockableAttr>;
@@ -1,13 +1,17 @@
 use proc_macro2::Span;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Ident, Lit, Meta, MetaNameValue, NestedMeta};
+use syn::{AttributeArgs, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
 
 use crate::constant::{
-    ATTR_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME, REMOTE_ATTR_PARAM_NAME,
-    STATIC_REFERENCES_ATTR_PARAM_NAME,
+    ASSOCIATED_CONST_ATTR_PARAM_NAME, ASSOCIATED_TYPE_ATTR_PARAM_NAME, ASYNC_TRAIT_ATTR_PARAM_NAME,
+    ATTR_NAME, LENIENT_ATTR_PARAM_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME,
+    OBJECT_SAFE_ATTR_PARAM_NAME, REMOTE_ATTR_PARAM_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME,
+    SYNC_ATTR_PARAM_NAME, USE_DEFAULT_ATTR_PARAM_NAME,
 };
 use crate::diagnostic::DiagnosticBuilder;
-use crate::parse::mockable_attr::{MockableAttr, MockableAttrParser, RemoteTraitPath};
+use crate::parse::mockable_attr::{
+    AssociatedConst, AssociatedType, MockableAttr, MockableAttrParser, RemoteTraitPath, UseDefault,
+};
 use crate::result::{merge_results, Error, Result};
 
 #[derive(Default, Debug)]
@@ -32,6 +36,20 @@ fn parse_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAt
         parse_static_references_meta_item(mockable_attr, item)
     } else if item.path().is_ident(REMOTE_ATTR_PARAM_NAME) {
         parse_remote_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(SYNC_ATTR_PARAM_NAME) {
+        parse_sync_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(OBJECT_SAFE_ATTR_PARAM_NAME) {
+        parse_object_safe_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(LENIENT_ATTR_PARAM_NAME) {
+        parse_lenient_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(USE_DEFAULT_ATTR_PARAM_NAME) {
+        parse_use_default_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(ASSOCIATED_TYPE_ATTR_PARAM_NAME) {
+        parse_associated_type_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(ASSOCIATED_CONST_ATTR_PARAM_NAME) {
+        parse_associated_const_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(ASYNC_TRAIT_ATTR_PARAM_NAME) {
+        parse_async_trait_meta_item(mockable_attr, item)
     } else {
         Err(attribute_property_not_supported_error(&item))
     }
@@ -56,7 +74,7 @@ fn parse_static_references_meta_item(
     if mockable_attr.force_static_lifetimes {
         Err(static_references_specified_more_than_once_error(&item))
     } else {
-        validate_static_references_property(&item)?;
+        validate_flag_property(STATIC_REFERENCES_ATTR_PARAM_NAME, &item)?;
         Ok(MockableAttr {
             force_static_lifetimes: true,
             ..mockable_attr
@@ -80,6 +98,126 @@ fn parse_remote_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<Moc
     }
 }
 
+fn parse_sync_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.sync {
+        Err(parameter_specified_more_than_once_error(
+            SYNC_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_flag_property(SYNC_ATTR_PARAM_NAME, &item)?;
+        Ok(MockableAttr {
+            sync: true,
+            ..mockable_attr
+        })
+    }
+}
+
+fn parse_object_safe_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.object_safe {
+        Err(parameter_specified_more_than_once_error(
+            OBJECT_SAFE_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_flag_property(OBJECT_SAFE_ATTR_PARAM_NAME, &item)?;
+        Ok(MockableAttr {
+            object_safe: true,
+            ..mockable_attr
+        })
+    }
+}
+
+fn parse_lenient_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.lenient {
+        Err(parameter_specified_more_than_once_error(
+            LENIENT_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_flag_property(LENIENT_ATTR_PARAM_NAME, &item)?;
+        Ok(MockableAttr {
+            lenient: true,
+            ..mockable_attr
+        })
+    }
+}
+
+fn parse_async_trait_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.async_trait {
+        Err(parameter_specified_more_than_once_error(
+            ASYNC_TRAIT_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_flag_property(ASYNC_TRAIT_ATTR_PARAM_NAME, &item)?;
+        Ok(MockableAttr {
+            async_trait: true,
+            ..mockable_attr
+        })
+    }
+}
+
+fn parse_use_default_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    match mockable_attr.use_default {
+        Some(_) => Err(parameter_specified_more_than_once_error(
+            USE_DEFAULT_ATTR_PARAM_NAME,
+            &item,
+        )),
+        None => {
+            let use_default = Some(parse_use_default_property(item)?);
+            Ok(MockableAttr {
+                use_default,
+                ..mockable_attr
+            })
+        }
+    }
+}
+
+fn parse_associated_type_meta_item(
+    mut mockable_attr: MockableAttr,
+    item: Meta,
+) -> Result<MockableAttr> {
+    for associated_type in parse_associated_type_property(item)? {
+        let is_duplicate = mockable_attr
+            .associated_types
+            .iter()
+            .any(|existing| existing.ident == associated_type.ident);
+
+        if is_duplicate {
+            return Err(associated_type_specified_more_than_once_error(
+                &associated_type.ident,
+            ));
+        }
+
+        mockable_attr.associated_types.push(associated_type);
+    }
+
+    Ok(mockable_attr)
+}
+
+fn parse_associated_const_meta_item(
+    mut mockable_attr: MockableAttr,
+    item: Meta,
+) -> Result<MockableAttr> {
+    for associated_const in parse_associated_const_property(item)? {
+        let is_duplicate = mockable_attr
+            .associated_consts
+            .iter()
+            .any(|existing| existing.ident == associated_const.ident);
+
+        if is_duplicate {
+            return Err(associated_const_specified_more_than_once_error(
+                &associated_const.ident,
+            ));
+        }
+
+        mockable_attr.associated_consts.push(associated_const);
+    }
+
+    Ok(mockable_attr)
+}
+
 fn get_meta_items(args: AttributeArgs) -> Result<impl Iterator<Item = Meta>> {
     let meta_items = args.into_iter().map(|nested| match nested {
         NestedMeta::Meta(meta) => Ok(meta),
@@ -91,10 +229,12 @@ fn get_meta_items(args: AttributeArgs) -> Result<impl Iterator<Item = Meta>> {
 fn parse_name_property(meta_item: Meta) -> Result<Ident> {
     let meta_item_span = meta_item.span();
 
-    if let Meta::NameValue(MetaNameValue { lit, .. }) = meta_item {
-        if let Lit::Str(str_lit) = lit {
-            return Ok(Ident::new(&str_lit.value(), str_lit.span()));
-        }
+    if let Meta::NameValue(MetaNameValue {
+        lit: Lit::Str(str_lit),
+        ..
+    }) = meta_item
+    {
+        return Ok(Ident::new(&str_lit.value(), str_lit.span()));
     }
 
     Err(invalid_name_property_syntax_error(meta_item_span))
@@ -116,6 +256,172 @@ fn parse_remote_property(meta_item: Meta) -> Result<RemoteTraitPath> {
     }
 }
 
+fn parse_use_default_property(meta_item: Meta) -> Result<UseDefault> {
+    let meta_item_span = meta_item.span();
+
+    match meta_item {
+        Meta::Path(_) => Ok(UseDefault::AllMethods),
+        Meta::List(MetaList { nested, .. }) if !nested.is_empty() => {
+            let methods = nested.into_iter().map(parse_use_default_method);
+            Ok(UseDefault::Methods(merge_results(methods)?.collect()))
+        }
+        _ => Err(invalid_use_default_property_syntax_error(meta_item_span)),
+    }
+}
+
+fn parse_use_default_method(nested_meta: NestedMeta) -> Result<Ident> {
+    let nested_meta_span = nested_meta.span();
+
+    if let NestedMeta::Meta(Meta::Path(path)) = nested_meta {
+        if let Some(ident) = path.get_ident() {
+            return Ok(ident.clone());
+        }
+    }
+
+    Err(invalid_use_default_property_syntax_error(nested_meta_span))
+}
+
+fn invalid_use_default_property_syntax_error(span: Span) -> Error {
+    let error_message = format!(
+        "#[{attr}({param}(...)) expects a list of method names",
+        attr = ATTR_NAME,
+        param = USE_DEFAULT_ATTR_PARAM_NAME
+    );
+    let help_message = format!(
+        "Example usage: #[{attr}({param})] or #[{attr}({param}(greet, leave))]",
+        attr = ATTR_NAME,
+        param = USE_DEFAULT_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn parse_associated_type_property(meta_item: Meta) -> Result<Vec<AssociatedType>> {
+    let meta_item_span = meta_item.span();
+
+    match meta_item {
+        Meta::List(MetaList { nested, .. }) if !nested.is_empty() => {
+            let associated_types = nested.into_iter().map(parse_associated_type);
+            Ok(merge_results(associated_types)?.collect())
+        }
+        _ => Err(invalid_associated_type_property_syntax_error(
+            meta_item_span,
+        )),
+    }
+}
+
+fn parse_associated_type(nested_meta: NestedMeta) -> Result<AssociatedType> {
+    let nested_meta_span = nested_meta.span();
+
+    if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+        path,
+        lit: Lit::Str(str_lit),
+        ..
+    })) = nested_meta
+    {
+        if let Some(ident) = path.get_ident() {
+            let ty = str_lit
+                .parse()
+                .map_err(|err| invalid_associated_type_property_syntax_error(err.span()))?;
+            return Ok(AssociatedType {
+                ident: ident.clone(),
+                ty,
+            });
+        }
+    }
+
+    Err(invalid_associated_type_property_syntax_error(
+        nested_meta_span,
+    ))
+}
+
+fn invalid_associated_type_property_syntax_error(span: Span) -> Error {
+    let error_message = format!(
+        "#[{attr}({param}(...)) expects a list of associated types with their types as string literals",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_TYPE_ATTR_PARAM_NAME
+    );
+    let help_message = format!(
+        "Example usage: #[{attr}({param}(Item = \"u32\"))]",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_TYPE_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn associated_type_specified_more_than_once_error(ident: &Ident) -> Error {
+    let error_message = format!(
+        "The associated type `{}` is specified more than once.",
+        ident
+    );
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
+fn parse_associated_const_property(meta_item: Meta) -> Result<Vec<AssociatedConst>> {
+    let meta_item_span = meta_item.span();
+
+    match meta_item {
+        Meta::List(MetaList { nested, .. }) if !nested.is_empty() => {
+            let associated_consts = nested.into_iter().map(parse_associated_const);
+            Ok(merge_results(associated_consts)?.collect())
+        }
+        _ => Err(invalid_associated_const_property_syntax_error(
+            meta_item_span,
+        )),
+    }
+}
+
+fn parse_associated_const(nested_meta: NestedMeta) -> Result<AssociatedConst> {
+    let nested_meta_span = nested_meta.span();
+
+    if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = nested_meta {
+        if let Some(ident) = path.get_ident() {
+            return Ok(AssociatedConst {
+                ident: ident.clone(),
+                value: lit,
+            });
+        }
+    }
+
+    Err(invalid_associated_const_property_syntax_error(
+        nested_meta_span,
+    ))
+}
+
+fn invalid_associated_const_property_syntax_error(span: Span) -> Error {
+    let error_message = format!(
+        "#[{attr}({param}(...)) expects a list of associated constants with literals as their values",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_CONST_ATTR_PARAM_NAME
+    );
+    let help_message = format!(
+        "Example usage: #[{attr}({param}(NAME = \"foo\", VERSION = 3))]",
+        attr = ATTR_NAME,
+        param = ASSOCIATED_CONST_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn associated_const_specified_more_than_once_error(ident: &Ident) -> Error {
+    let error_message = format!(
+        "The associated constant `{}` is specified more than once.",
+        ident
+    );
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
 fn invalid_remote_property_syntax_error(span: Span) -> Error {
     let error_message = format!(
         "#[{attr}({param} = \"...\") must be a valid path",
@@ -150,27 +456,19 @@ fn invalid_name_property_syntax_error(span: Span) -> Error {
         .into()
 }
 
-fn validate_static_references_property(meta_item: &Meta) -> Result<()> {
+fn validate_flag_property(name: &str, meta_item: &Meta) -> Result<()> {
     let meta_item_span = meta_item.span();
 
     if let Meta::Path(_) = meta_item {
         Ok(())
     } else {
-        Err(invalid_static_references_property_syntax_error(
-            meta_item_span,
-        ))
+        Err(invalid_flag_property_syntax_error(name, meta_item_span))
     }
 }
 
-fn invalid_static_references_property_syntax_error(span: Span) -> Error {
-    let error_message = format!(
-        "#[{}({}) does not take any parameters",
-        ATTR_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME
-    );
-    let help_message = format!(
-        "Correct usage: #[{}({})]",
-        ATTR_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME
-    );
+fn invalid_flag_property_syntax_error(name: &str, span: Span) -> Error {
+    let error_message = format!("#[{}({}) does not take any parameters", ATTR_NAME, name);
+    let help_message = format!("Correct usage: #[{}({})]", ATTR_NAME, name);
     DiagnosticBuilder::error(span, error_message)
         .help(help_message)
         .build()
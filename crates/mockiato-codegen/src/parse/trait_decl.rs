@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use proc_macro2::Span;
 use syn::punctuated::Punctuated;
-use syn::{Generics, Ident, ItemTrait, Token, TypeParamBound, Visibility};
+use syn::{Generics, Ident, ItemTrait, Token, Type, TypeParamBound, Visibility};
 
 use crate::parse::method_decl::MethodDecl;
 use crate::result::Result;
@@ -15,8 +15,22 @@ pub(crate) struct TraitDecl {
     pub(crate) ident: Ident,
     pub(crate) generics: Generics,
     pub(crate) unsafety: Option<Token![unsafe]>,
+    #[allow(dead_code)]
     pub(crate) supertraits: Punctuated<TypeParamBound, Token![+]>,
     pub(crate) methods: Vec<MethodDecl>,
+    /// The identifiers of the trait's associated types
+    pub(crate) associated_types: Vec<Ident>,
+    pub(crate) associated_consts: Vec<AssociatedConstDecl>,
+}
+
+/// An associated constant declared by the trait. (E.g. `const NAME: &'static str;`)
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedConstDecl {
+    pub(crate) ident: Ident,
+    pub(crate) ty: Type,
+    /// Whether the trait provides a default value for this constant
+    pub(crate) has_default: bool,
 }
 
 #[cfg_attr(test, mockiato::mockable)]
@@ -1,13 +1,10 @@
-use std::collections::HashSet;
-
-use proc_macro2::Ident;
 use syn::spanned::Spanned;
-use syn::{GenericParam, Generics, ItemTrait};
+use syn::{GenericParam, Generics, Ident, ItemTrait, TraitItem, TraitItemConst, TraitItemType};
 
 use crate::diagnostic::DiagnosticBuilder;
 use crate::parse::check_option_is_none;
 use crate::parse::method_decl::MethodDeclParser;
-use crate::parse::trait_decl::{TraitDecl, TraitDeclParser};
+use crate::parse::trait_decl::{AssociatedConstDecl, TraitDecl, TraitDeclParser};
 use crate::result::{merge_results, Error, Result};
 
 #[derive(Debug)]
@@ -38,10 +35,21 @@ impl TraitDeclParser for TraitDeclParserImpl {
         check_option_is_none(&auto_token, span, "Auto traits are not supported")?;
         validate_generic_type_parameters(&generics)?;
 
-        let generic_types = collect_generic_type_idents(&generics);
-        let methods = items
-            .into_iter()
-            .map(move |method| self.method_decl_parser.parse(method, &generic_types));
+        let mut associated_types = Vec::new();
+        let mut associated_consts = Vec::new();
+        let mut methods = Vec::new();
+
+        for item in items {
+            match item {
+                TraitItem::Type(associated_type) => {
+                    associated_types.push(parse_associated_type(associated_type))
+                }
+                TraitItem::Const(associated_const) => {
+                    associated_consts.push(parse_associated_const(associated_const))
+                }
+                item => methods.push(self.method_decl_parser.parse(item)),
+            }
+        }
 
         Ok(TraitDecl {
             visibility,
@@ -50,7 +58,9 @@ impl TraitDeclParser for TraitDeclParserImpl {
             unsafety,
             generics,
             supertraits,
-            methods: merge_results(methods)?.collect(),
+            methods: merge_results(methods.into_iter())?.collect(),
+            associated_types: merge_results(associated_types.into_iter())?.collect(),
+            associated_consts,
         })
     }
 }
@@ -74,15 +84,25 @@ fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
     merge_results(results).map(|_| ())
 }
 
-fn collect_generic_type_idents(generics: &Generics) -> HashSet<Ident> {
-    generics
-        .params
-        .iter()
-        .filter_map(|param| match param {
-            GenericParam::Type(param_ty) => Some(param_ty.ident.clone()),
-            _ => None,
-        })
-        .collect()
+fn parse_associated_type(associated_type: TraitItemType) -> Result<Ident> {
+    if associated_type.generics.params.is_empty() {
+        Ok(associated_type.ident)
+    } else {
+        Err(DiagnosticBuilder::error(
+            associated_type.generics.span(),
+            "Generic associated types are not supported",
+        )
+        .build()
+        .into())
+    }
+}
+
+fn parse_associated_const(associated_const: TraitItemConst) -> AssociatedConstDecl {
+    AssociatedConstDecl {
+        ident: associated_const.ident,
+        ty: associated_const.ty,
+        has_default: associated_const.default.is_some(),
+    }
 }
 
 fn invalid_generic_param_error(generic_param: &GenericParam, message: &str) -> Error {
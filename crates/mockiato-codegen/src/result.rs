@@ -15,8 +15,7 @@ impl FromIterator<Error> for Error {
     {
         let diagnostics = iter
             .into_iter()
-            .map(|error| error.diagnostics.into_iter())
-            .flatten()
+            .flat_map(|error| error.diagnostics.into_iter())
             .collect();
         Self { diagnostics }
     }
@@ -3,4 +3,5 @@
 fn ui_tests() {
     let test_cases = trybuild::TestCases::new();
     test_cases.compile_fail("tests/ui/*.rs");
+    test_cases.pass("tests/pass/*.rs");
 }
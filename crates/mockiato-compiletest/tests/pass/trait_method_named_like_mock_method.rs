@@ -0,0 +1,49 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Validator {
+    fn verify(&self, value: u32) -> bool;
+
+    fn try_verify(&self, value: u32) -> bool;
+
+    fn checkpoint(&self, name: &str);
+}
+
+#[mockable(lenient)]
+trait Reporter {
+    fn allow_unexpected_calls(&self) -> bool;
+
+    fn unexpected_calls(&self) -> u32;
+}
+
+fn trait_methods_named_like_verification_methods_can_be_called() {
+    let mut validator = ValidatorMock::new();
+
+    validator
+        .expect_verify(|arg| arg.partial_eq(1))
+        .returns(true);
+    validator
+        .expect_try_verify(|arg| arg.partial_eq(2))
+        .returns(false);
+    validator
+        .expect_checkpoint(|arg| arg.partial_eq("done"))
+        .returns(());
+
+    assert!(validator.verify(1));
+    assert!(!validator.try_verify(2));
+    validator.checkpoint("done");
+}
+
+fn trait_methods_named_like_lenient_methods_can_be_called() {
+    let mut reporter = ReporterMock::new();
+
+    reporter.fallback_unexpected_calls().returns(3);
+
+    assert!(!reporter.allow_unexpected_calls());
+    assert_eq!(3, reporter.unexpected_calls());
+}
+
+fn main() {
+    trait_methods_named_like_verification_methods_can_be_called();
+    trait_methods_named_like_lenient_methods_can_be_called();
+}
@@ -0,0 +1,23 @@
+use mockiato::mockable;
+
+#[mockable(associated_const)]
+trait TraitOne {
+    const NAME: &'static str;
+}
+
+#[mockable(associated_const(NAME))]
+trait TraitTwo {
+    const NAME: &'static str;
+}
+
+#[mockable(associated_const(NAME = "foo"), associated_const(NAME = "bar"))]
+trait TraitThree {
+    const NAME: &'static str;
+}
+
+#[mockable(associated_const(VERSION = 1))]
+trait TraitFour {
+    const NAME: &'static str;
+}
+
+fn main() {}
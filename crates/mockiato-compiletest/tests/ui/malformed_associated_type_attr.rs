@@ -0,0 +1,23 @@
+use mockiato::mockable;
+
+#[mockable(associated_type)]
+trait TraitOne {
+    type Item;
+}
+
+#[mockable(associated_type(Item))]
+trait TraitTwo {
+    type Item;
+}
+
+#[mockable(associated_type(Item = "u32"), associated_type(Item = "u64"))]
+trait TraitThree {
+    type Item;
+}
+
+#[mockable(associated_type(Output = "u32"))]
+trait TraitFour {
+    type Item;
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use mockiato::mockable;
+
+#[mockable(use_default = "greet")]
+trait TraitOne {
+    fn greet(&self) {}
+}
+
+#[mockable(use_default("greet"))]
+trait TraitTwo {
+    fn greet(&self) {}
+}
+
+#[mockable(use_default(leave))]
+trait TraitThree {
+    fn greet(&self) {}
+}
+
+#[mockable(use_default(greet))]
+trait TraitFour {
+    fn greet(&self);
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use mockiato::mockable;
+
+trait Serialize {}
+
+#[mockable]
+trait Handler {
+    fn handle<T: Serialize>(&self, value: T);
+}
+
+fn main() {}
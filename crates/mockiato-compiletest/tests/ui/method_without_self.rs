@@ -1,6 +1,6 @@
 use mockiato::mockable;
 
-#[mockable]
+#[mockable(object_safe)]
 trait Foo {
     fn bar(baz: u64);
 }
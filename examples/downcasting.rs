@@ -2,6 +2,8 @@
 // This is an issue, because there's no way to sensibly mock this method with mockiato.
 // The solution is to split the `as_any` method into a separate trait.
 
+#![allow(dead_code)]
+
 #[cfg(test)]
 use mockiato::mockable;
 use std::any::Any;
@@ -32,3 +34,5 @@ fn object_behavior_can_be_downcast() {
     let concrete_behavior: &ObjectBehaviorMock = behavior.as_any().downcast_ref().unwrap();
     dbg!(&concrete_behavior);
 }
+
+fn main() {}
@@ -10,3 +10,21 @@ impl Argument {
         Self(PhantomData)
     }
 }
+
+/// A closure that creates an argument matcher from an [`Argument`].
+///
+/// This is used by the `expect_*` methods of generic methods, so that the type parameters
+/// of the method are the only generic parameters that need to be specified.
+pub trait ArgumentMatcherFactory:
+    FnOnce(&Argument) -> <Self as ArgumentMatcherFactory>::Matcher
+{
+    /// The argument matcher created by this factory
+    type Matcher;
+}
+
+impl<F, M> ArgumentMatcherFactory for F
+where
+    F: FnOnce(&Argument) -> M,
+{
+    type Matcher = M;
+}
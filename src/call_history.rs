@@ -0,0 +1,285 @@
+use crate::method::FOOTER;
+use std::fmt::{self, Display};
+use std::slice;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Identifies the order of calls across all methods and mocks.
+static NEXT_CALL_ORDINAL: AtomicU64 = AtomicU64::new(0);
+
+/// The calls that were made to a mocked method, in the order in which they were made.
+/// It is returned by the `calls_to_<method_name>` methods on a generated mock.
+///
+/// Every call that the mock answered is recorded, including calls that were answered
+/// by a fallback or forwarded to the real implementation.
+/// The arguments of a call are recorded as a struct with a [`Recorded`](crate::Recorded) field
+/// for each argument, see [`Recorded`](crate::Recorded) for which arguments can be recorded.
+/// Arguments whose type contains a lifetime or a type parameter are not part of the struct.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str, times: u32) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.any(), |arg| arg.any())
+///     .times(..)
+///     .returns(String::from("Hello"));
+///
+/// greeter.greet("Jane", 1);
+/// greeter.greet("John", 2);
+///
+/// let calls = greeter.calls_to_greet();
+///
+/// calls.assert_called_times(2);
+/// calls.assert_called_with(|args| &*args.name == "Jane" && *args.times == 1);
+/// calls.assert_last_called_with(|args| &*args.name == "John");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CallHistory<T> {
+    method_name: String,
+    calls: Vec<RecordedCall<T>>,
+}
+
+impl<T> CallHistory<T> {
+    pub(crate) fn new<I>(method_name: String, calls: I) -> Self
+    where
+        I: IntoIterator<Item = RecordedCall<T>>,
+    {
+        let mut calls: Vec<_> = calls.into_iter().collect();
+        calls.sort_by_key(|call| call.ordinal);
+
+        Self { method_name, calls }
+    }
+
+    /// Returns the number of recorded calls.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns `true` if the method was never called.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Returns the call at the given position, starting with the first call.
+    pub fn get(&self, index: usize) -> Option<&RecordedCall<T>> {
+        self.calls.get(index)
+    }
+
+    /// Returns the most recent call.
+    pub fn last(&self) -> Option<&RecordedCall<T>> {
+        self.calls.last()
+    }
+
+    /// Returns an iterator over the recorded calls, starting with the first call.
+    pub fn iter(&self) -> slice::Iter<'_, RecordedCall<T>> {
+        self.calls.iter()
+    }
+
+    /// Panics if the method was never called.
+    pub fn assert_called(&self) {
+        if self.calls.is_empty() {
+            panic!(
+                "\n\n{} was expected to be called, but it was never called.\n{}",
+                self.method_name, FOOTER
+            );
+        }
+    }
+
+    /// Panics if the method was called.
+    pub fn assert_not_called(&self) {
+        if !self.calls.is_empty() {
+            panic!(
+                "\n\n{} was not expected to be called, but it was called {} time(s).\n{}{}",
+                self.method_name,
+                self.calls.len(),
+                self,
+                FOOTER
+            );
+        }
+    }
+
+    /// Panics if the method was not called exactly `times` times.
+    pub fn assert_called_times(&self, times: u64) {
+        if self.calls.len() as u64 != times {
+            panic!(
+                "\n\n{} was expected to be called {} time(s), but it was called {} time(s).\n{}{}",
+                self.method_name,
+                times,
+                self.calls.len(),
+                self,
+                FOOTER
+            );
+        }
+    }
+
+    /// Panics if `predicate` returns `false` for the arguments of every call.
+    pub fn assert_called_with<F>(&self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        if !self.calls.iter().any(|call| predicate(&call.arguments)) {
+            panic!(
+                "\n\n{} was expected to be called with matching arguments, but none of the calls matched.\n{}{}",
+                self.method_name, self, FOOTER
+            );
+        }
+    }
+
+    /// Panics if the method was never called or `predicate` returns `false`
+    /// for the arguments of the most recent call.
+    pub fn assert_last_called_with<F>(&self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        if !self
+            .calls
+            .last()
+            .is_some_and(|call| predicate(&call.arguments))
+        {
+            panic!(
+                "\n\nThe last call to {} was expected to be made with matching arguments.\n{}{}",
+                self.method_name, self, FOOTER
+            );
+        }
+    }
+}
+
+impl<T> Display for CallHistory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.calls.is_empty() {
+            return writeln!(f, "No calls to {} were made.", self.method_name);
+        }
+
+        writeln!(f, "The following calls were made:")?;
+
+        for call in &self.calls {
+            writeln!(f, "{}{}", self.method_name, call)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CallHistory<T> {
+    type Item = &'a RecordedCall<T>;
+    type IntoIter = slice::Iter<'a, RecordedCall<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A call to a mocked method that was recorded by the mock.
+#[derive(Debug, Clone)]
+pub struct RecordedCall<T> {
+    ordinal: u64,
+    arguments: T,
+    formatted_arguments: String,
+}
+
+impl<T> RecordedCall<T> {
+    pub(crate) fn new(arguments: T, formatted_arguments: String) -> Self {
+        Self {
+            ordinal: NEXT_CALL_ORDINAL.fetch_add(1, Ordering::SeqCst),
+            arguments,
+            formatted_arguments,
+        }
+    }
+
+    /// The recorded arguments of the call.
+    pub fn arguments(&self) -> &T {
+        &self.arguments
+    }
+}
+
+impl<T> Display for RecordedCall<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.formatted_arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn recorded_call(argument: u32) -> RecordedCall<u32> {
+        RecordedCall::new(argument, format!("({})", argument))
+    }
+
+    fn call_history(arguments: &[u32]) -> CallHistory<u32> {
+        CallHistory::new(
+            String::from("test"),
+            arguments.iter().copied().map(recorded_call),
+        )
+    }
+
+    #[test]
+    fn calls_are_sorted_in_the_order_they_were_made() {
+        let first_call = recorded_call(1);
+        let second_call = recorded_call(2);
+
+        let history = CallHistory::new(String::from("test"), vec![second_call, first_call]);
+
+        let arguments: Vec<_> = history.iter().map(RecordedCall::arguments).collect();
+        assert_eq!(vec![&1, &2], arguments);
+    }
+
+    #[test]
+    fn assert_called_with_accepts_any_matching_call() {
+        call_history(&[1, 2]).assert_called_with(|argument| *argument == 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "test was expected to be called with matching arguments")]
+    fn assert_called_with_panics_if_no_call_matches() {
+        call_history(&[1, 2]).assert_called_with(|argument| *argument == 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The last call to test was expected to be made with matching arguments"
+    )]
+    fn assert_last_called_with_panics_if_last_call_does_not_match() {
+        call_history(&[1, 2]).assert_last_called_with(|argument| *argument == 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The last call to test was expected to be made with matching arguments"
+    )]
+    fn assert_last_called_with_panics_if_there_are_no_calls() {
+        call_history(&[]).assert_last_called_with(|_| true);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "test was expected to be called 1 time(s), but it was called 2 time(s)"
+    )]
+    fn assert_called_times_panics_if_number_of_calls_differs() {
+        call_history(&[1, 2]).assert_called_times(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "test was expected to be called, but it was never called")]
+    fn assert_called_panics_if_there_are_no_calls() {
+        call_history(&[]).assert_called();
+    }
+
+    #[test]
+    fn calls_are_displayed_with_their_formatted_arguments() {
+        assert_eq!(
+            "The following calls were made:\ntest(1)\ntest(2)\n",
+            call_history(&[1, 2]).to_string()
+        );
+    }
+}
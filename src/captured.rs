@@ -0,0 +1,143 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A slot that stores the arguments captured by [`Argument::capture`].
+///
+/// Every argument of an accepted call is cloned into the slot, so that it can be
+/// inspected after the call, e.g. to use a generated id later in the test.
+/// References are captured as owned values, e.g. `&str` is captured as [`String`].
+/// The type of the slot needs to be annotated if it can't be inferred from its usage.
+///
+/// [`Argument::capture`]: crate::Argument::capture
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Captured};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Scheduler {
+///     fn schedule(&self, callback_id: u32);
+/// }
+///
+/// let callback_id = Captured::new();
+/// let mut scheduler = SchedulerMock::new();
+/// scheduler
+///     .expect_schedule(|arg| arg.capture(&callback_id))
+///     .returns(());
+///
+/// scheduler.schedule(42);
+///
+/// assert_eq!(42, callback_id.single());
+/// ```
+#[derive(Debug)]
+pub struct Captured<T> {
+    values: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T> Captured<T> {
+    /// Creates a new empty slot.
+    pub fn new() -> Self {
+        Self {
+            values: Arc::default(),
+        }
+    }
+
+    pub(crate) fn push(&self, value: T) {
+        self.values().push(value);
+    }
+
+    fn values(&self) -> MutexGuard<'_, Vec<T>> {
+        self.values
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl<T> Captured<T>
+where
+    T: Clone,
+{
+    /// Returns the argument that was captured last, if any.
+    pub fn last(&self) -> Option<T> {
+        self.values().last().cloned()
+    }
+
+    /// Returns all captured arguments in the order in which they were captured.
+    pub fn all(&self) -> Vec<T> {
+        self.values().clone()
+    }
+
+    /// Returns the only captured argument.
+    ///
+    /// # Panics
+    /// Panics if no argument or more than one argument was captured.
+    pub fn single(&self) -> T {
+        let values = self.values();
+
+        match values.as_slice() {
+            [value] => value.clone(),
+            _ => panic!(
+                "Expected exactly one captured argument, but {} were captured",
+                values.len()
+            ),
+        }
+    }
+}
+
+impl<T> Default for Captured<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates another handle to the same slot.
+impl<T> Clone for Captured<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: Arc::clone(&self.values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accessors_return_captured_values() {
+        let captured = Captured::new();
+        captured.push(1);
+        captured.push(2);
+
+        assert_eq!(Some(2), captured.last());
+        assert_eq!(vec![1, 2], captured.all());
+    }
+
+    #[test]
+    fn last_returns_none_without_captured_values() {
+        let captured: Captured<u32> = Captured::new();
+
+        assert_eq!(None, captured.last());
+        assert!(captured.all().is_empty());
+    }
+
+    #[test]
+    fn clones_share_values() {
+        let captured = Captured::new();
+        captured.clone().push(1);
+
+        assert_eq!(1, captured.single());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected exactly one captured argument, but 2 were captured")]
+    fn single_panics_with_more_than_one_value() {
+        let captured = Captured::new();
+        captured.push(1);
+        captured.push(2);
+
+        captured.single();
+    }
+}
@@ -1,33 +1,39 @@
 #[cfg(rustc_is_nightly)]
 use crate::matcher::ArgumentsMatcher;
-use crate::return_value::ReturnValueGenerator;
-use std::rc::Rc;
+use crate::storage::{Shared, UsesStorage};
 
 #[cfg(rustc_is_nightly)]
-use crate::return_value::Cloned;
+use crate::return_value::{Cloned, FromReturnValueGenerator};
 
-pub(crate) trait DefaultReturnValue<A>: Sized {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
+pub(crate) trait DefaultReturnValue<A, G>: Sized
+where
+    G: UsesStorage + ?Sized,
+{
+    fn default_return_value() -> Option<Shared<G, G>> {
         None
     }
 }
 
 #[cfg(not(rustc_is_nightly))]
-impl<A, T> DefaultReturnValue<A> for T {}
+impl<A, G, T> DefaultReturnValue<A, G> for T where G: UsesStorage + ?Sized {}
 
 #[cfg(rustc_is_nightly)]
-impl<A, T> DefaultReturnValue<A> for T {
-    default fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
+impl<A, G, T> DefaultReturnValue<A, G> for T
+where
+    G: UsesStorage + ?Sized,
+{
+    default fn default_return_value() -> Option<Shared<G, G>> {
         None
     }
 }
 
 #[cfg(rustc_is_nightly)]
-impl<A> DefaultReturnValue<A> for ()
+impl<A, G> DefaultReturnValue<A, G> for ()
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: FromReturnValueGenerator<Cloned<()>> + ?Sized,
 {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, ()>>> {
-        Some(Rc::new(Cloned(())))
+    fn default_return_value() -> Option<Shared<G, G>> {
+        Some(G::from_return_value_generator(Cloned(())))
     }
 }
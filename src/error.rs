@@ -0,0 +1,359 @@
+use crate::expected_calls::ExpectedCalls;
+use crate::fmt::DisplayTimes;
+use crate::method::FOOTER;
+use crate::sequence::SequenceError;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// All problems that were found in a mock.
+///
+/// It is returned by the `verify` method on a generated mock.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MockError {
+    call_errors: Vec<CallError>,
+    verification_errors: Vec<VerificationError>,
+}
+
+impl MockError {
+    /// The calls to the mock that were not expected.
+    pub fn call_errors(&self) -> &[CallError] {
+        &self.call_errors
+    }
+
+    /// The methods whose expected calls were not satisfied.
+    pub fn verification_errors(&self) -> &[VerificationError] {
+        &self.verification_errors
+    }
+}
+
+impl Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for call_error in &self.call_errors {
+            writeln!(f, "{}", call_error)?;
+        }
+
+        for verification_error in &self.verification_errors {
+            writeln!(f, "{}", verification_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for MockError {}
+
+/// A call to a mocked method that was not expected.
+///
+/// The mock panics with this error when the call is made.
+/// The error is also kept by the mock, so that it can be retrieved using `verify`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CallError {
+    method_name: String,
+    arguments: String,
+    kind: CallErrorKind,
+}
+
+/// Describes why a call to a mocked method was not expected.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CallErrorKind {
+    /// None of the expected calls matched the call.
+    NoMatchingCall {
+        /// All expected calls of the method.
+        expected_calls: Vec<ExpectedCallReport>,
+    },
+    /// More than one expected call matched the call.
+    MoreThanOneMatchingCall {
+        /// The expected calls that matched the call.
+        matching_calls: Vec<ExpectedCallReport>,
+    },
+    /// The call matched an expected call, but it was made out of the order defined by a [`Sequence`].
+    ///
+    /// [`Sequence`]: crate::Sequence
+    OutOfSequence(SequenceError),
+    /// No calls were expected for the type parameters of a generic method.
+    UnexpectedTypeParameters {
+        /// The type parameters of the call.
+        type_parameters: &'static str,
+        /// The type parameters for which calls were expected.
+        expected_type_parameters: Vec<&'static str>,
+    },
+}
+
+impl CallError {
+    pub(crate) fn new(method_name: String, arguments: String, kind: CallErrorKind) -> Self {
+        Self {
+            method_name,
+            arguments,
+            kind,
+        }
+    }
+
+    /// The name of the method that was called.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// The arguments of the call, formatted like a tuple of their [`Debug`] representations.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn arguments(&self) -> &str {
+        &self.arguments
+    }
+
+    /// Describes why the call was not expected.
+    pub fn kind(&self) -> &CallErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CallError {
+            method_name,
+            arguments,
+            kind,
+        } = self;
+
+        match kind {
+            CallErrorKind::NoMatchingCall { expected_calls } => {
+                writeln!(f, "The call {}{} was not expected.", method_name, arguments)?;
+
+                if expected_calls.is_empty() {
+                    writeln!(f, "No calls to {} were expected.", method_name)
+                } else {
+                    writeln!(
+                        f,
+                        "The following calls were expected:\n{}",
+                        DisplayExpectedCalls(expected_calls)
+                    )
+                }
+            }
+            CallErrorKind::MoreThanOneMatchingCall { matching_calls } => writeln!(
+                f,
+                "\nThe call {}{} matches more than one expected call:\n{}",
+                method_name,
+                arguments,
+                DisplayExpectedCalls(matching_calls)
+            ),
+            CallErrorKind::OutOfSequence(sequence_error) => writeln!(
+                f,
+                "The call {}{} was not expected at this point of the sequence.\n{}",
+                method_name, arguments, sequence_error
+            ),
+            CallErrorKind::UnexpectedTypeParameters {
+                type_parameters,
+                expected_type_parameters,
+            } => {
+                writeln!(
+                    f,
+                    "The call {}::<{}>{} was not expected.",
+                    method_name, type_parameters, arguments
+                )?;
+
+                if expected_type_parameters.is_empty() {
+                    writeln!(f, "No calls to {} were expected.", method_name)
+                } else {
+                    writeln!(
+                        f,
+                        "Calls to {} were only expected with these type parameters:",
+                        method_name
+                    )?;
+
+                    for expected_type_parameters in expected_type_parameters {
+                        writeln!(f, "{}::<{}>", method_name, expected_type_parameters)?;
+                    }
+
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+impl Error for CallError {}
+
+/// The expected calls of a mocked method were not satisfied.
+///
+/// It is returned as part of a [`MockError`] by the `verify` method on a generated mock.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerificationError {
+    method_name: String,
+    expected_calls: Vec<ExpectedCallReport>,
+}
+
+impl VerificationError {
+    pub(crate) fn new(method_name: String, expected_calls: Vec<ExpectedCallReport>) -> Self {
+        Self {
+            method_name,
+            expected_calls,
+        }
+    }
+
+    /// The name of the method whose expected calls were not satisfied.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// All expected calls of the method, including the ones that were satisfied.
+    pub fn expected_calls(&self) -> &[ExpectedCallReport] {
+        &self.expected_calls
+    }
+}
+
+impl Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "The expected calls for {} were not satisified.",
+            self.method_name
+        )?;
+
+        write!(f, "{}", DisplayExpectedCalls(&self.expected_calls))
+    }
+}
+
+impl Error for VerificationError {}
+
+/// An expected call of a mocked method and how often it was called.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpectedCallReport {
+    arguments: String,
+    return_value: String,
+    expected_calls: ExpectedCalls,
+    actual_number_of_calls: u64,
+}
+
+impl ExpectedCallReport {
+    pub(crate) fn new(
+        arguments: String,
+        return_value: String,
+        expected_calls: ExpectedCalls,
+        actual_number_of_calls: u64,
+    ) -> Self {
+        Self {
+            arguments,
+            return_value,
+            expected_calls,
+            actual_number_of_calls,
+        }
+    }
+
+    /// The method and the argument matchers of the expected call, e.g. `greet("Jane")`.
+    pub fn arguments(&self) -> &str {
+        &self.arguments
+    }
+
+    /// How often the call was expected.
+    pub fn expected_calls(&self) -> &ExpectedCalls {
+        &self.expected_calls
+    }
+
+    /// How often the call was actually made.
+    pub fn actual_number_of_calls(&self) -> u64 {
+        self.actual_number_of_calls
+    }
+
+    /// Returns `true` if the call was made as often as expected.
+    pub fn is_satisfied(&self) -> bool {
+        self.expected_calls.contains(self.actual_number_of_calls)
+    }
+}
+
+impl Display for ExpectedCallReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} {}, was called {}",
+            self.arguments,
+            self.return_value,
+            self.expected_calls,
+            DisplayTimes(self.actual_number_of_calls)
+        )
+    }
+}
+
+struct DisplayExpectedCalls<'a>(&'a [ExpectedCallReport]);
+
+impl<'a> Display for DisplayExpectedCalls<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for expected_call in self.0 {
+            writeln!(f, "{}", expected_call)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Panics with all errors of the mock, if there are any.
+pub fn mock_result_unwrap(result: Result<(), MockError>) {
+    if let Err(error) = result {
+        panic!("{}{}", error, FOOTER);
+    }
+}
+
+/// Combines the errors of all methods of a mock.
+pub fn mock_result(
+    call_errors: Vec<CallError>,
+    verification_errors: Vec<VerificationError>,
+) -> Result<(), MockError> {
+    if call_errors.is_empty() && verification_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(MockError {
+            call_errors,
+            verification_errors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expected_call_report(actual_number_of_calls: u64) -> ExpectedCallReport {
+        ExpectedCallReport::new(
+            String::from("greet(\"Jane\")"),
+            String::from("\"Hello\""),
+            ExpectedCalls::from(1),
+            actual_number_of_calls,
+        )
+    }
+
+    #[test]
+    fn expected_call_report_is_satisfied_if_number_of_calls_matches() {
+        assert!(expected_call_report(1).is_satisfied());
+        assert!(!expected_call_report(2).is_satisfied());
+    }
+
+    #[test]
+    fn expected_call_report_is_displayed_like_an_expected_call() {
+        assert_eq!(
+            "greet(\"Jane\") -> \"Hello\" exactly 1 time, was called 2 times",
+            expected_call_report(2).to_string()
+        );
+    }
+
+    #[test]
+    fn mock_result_is_ok_without_errors() {
+        assert!(mock_result(Vec::new(), Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn mock_result_contains_all_errors() {
+        let call_error = CallError::new(
+            String::from("greet"),
+            String::from("(\"John\")"),
+            CallErrorKind::NoMatchingCall {
+                expected_calls: vec![expected_call_report(0)],
+            },
+        );
+        let verification_error =
+            VerificationError::new(String::from("greet"), vec![expected_call_report(0)]);
+
+        let error =
+            mock_result(vec![call_error.clone()], vec![verification_error.clone()]).unwrap_err();
+
+        assert_eq!(&[call_error], error.call_errors());
+        assert_eq!(&[verification_error], error.verification_errors());
+    }
+}
@@ -32,6 +32,16 @@ impl ExpectedCalls {
         }
     }
 
+    pub(crate) fn min_value(&self) -> u64 {
+        match self.0 {
+            ExpectedCallsKind::Exact(expected) => expected,
+            ExpectedCallsKind::AtLeast(min) => min,
+            ExpectedCallsKind::Between { start, .. } => start,
+            ExpectedCallsKind::BetweenInclusive { start, .. } => start,
+            ExpectedCallsKind::AtMost(_) | ExpectedCallsKind::Any => 0,
+        }
+    }
+
     pub(crate) fn max_value(&self) -> Option<u64> {
         match self.0 {
             ExpectedCallsKind::Exact(expected) => Some(expected),
@@ -367,4 +377,21 @@ mod test {
     fn any_has_no_max_value() {
         assert!(ExpectedCalls::from(..).max_value().is_none());
     }
+
+    #[test]
+    fn at_least_has_min_value() {
+        assert_eq!(3, ExpectedCalls::from(3..).min_value());
+    }
+
+    #[test]
+    fn between_has_min_value() {
+        assert_eq!(1, ExpectedCalls::from(1..3).min_value());
+        assert_eq!(1, ExpectedCalls::from(1..=3).min_value());
+    }
+
+    #[test]
+    fn any_has_no_min_value() {
+        assert_eq!(0, ExpectedCalls::from(..).min_value());
+        assert_eq!(0, ExpectedCalls::from(..=3).min_value());
+    }
 }
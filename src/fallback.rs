@@ -0,0 +1,199 @@
+use crate::matcher::ArgumentsMatcher;
+use crate::return_value::{self, FromReturnValueGenerator, ReturnValueGenerator};
+use crate::storage::{Shared, UsesStorage};
+use nameof::name_of;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+/// Configures the return value of unexpected calls to a method.
+/// The fallback is only used once unexpected calls are allowed using `allow_unexpected_calls`
+/// or `#[mockable(lenient)]`.
+///
+/// This builder is returned from the `fallback_*` methods on a generated mock.
+pub struct FallbackBuilder<'mock, 'a, A, R, G = dyn ReturnValueGenerator<A, R> + 'mock>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
+{
+    fallback: &'a mut Option<Shared<G, G>>,
+    phantom_data: PhantomData<fn(A) -> R>,
+    mock_lifetime: PhantomData<&'mock ()>,
+}
+
+impl<'mock, 'a, A, R, G> Debug for FallbackBuilder<'mock, 'a, A, R, G>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type FallbackBuilder<'mock, 'a, A, R, G>))
+            .field(name_of!(fallback in Self), &self.fallback.as_deref())
+            .finish()
+    }
+}
+
+impl<'mock, 'a, A, R, G> FallbackBuilder<'mock, 'a, A, R, G>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
+{
+    /// Defines the return value for unexpected calls.
+    /// The value must be [`Clone`]able.
+    pub fn returns(&mut self, return_value: R) -> &mut Self
+    where
+        R: Clone + 'mock,
+        G: FromReturnValueGenerator<return_value::Cloned<R>>,
+    {
+        *self.fallback = Some(G::from_return_value_generator(return_value::Cloned(
+            return_value,
+        )));
+        self
+    }
+
+    /// Defines a closure that computes the return value for unexpected calls.
+    /// The closure receives the arguments of the call, which can be accessed by their name.
+    pub fn returns_with<F>(&mut self, closure: F) -> &mut Self
+    where
+        F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R + 'mock,
+        G: FromReturnValueGenerator<return_value::Closure<F>>,
+    {
+        *self.fallback = Some(G::from_return_value_generator(return_value::Closure(
+            closure,
+        )));
+        self
+    }
+
+    pub(crate) fn new(fallback: &'a mut Option<Shared<G, G>>) -> Self {
+        Self {
+            fallback,
+            phantom_data: PhantomData,
+            mock_lifetime: PhantomData,
+        }
+    }
+}
+
+/// Creates a fallback that returns [`Default::default()`] if the return type implements [`Default`].
+///
+/// The generated mocks call `(&method.default_fallback_factory()).default_fallback()`.
+/// Method resolution prefers [`DefaultFallback`], which is implemented for the factory itself,
+/// over [`NoDefaultFallback`], which is implemented for a reference to the factory.
+/// This only works when the return type is known, which is the case in the generated code.
+pub struct DefaultFallbackFactory<A, R, G>
+where
+    G: ?Sized,
+{
+    phantom_data: PhantomData<fn(A, &G) -> R>,
+}
+
+impl<A, R, G> DefaultFallbackFactory<A, R, G>
+where
+    G: ?Sized,
+{
+    /// Creates a new factory.
+    pub fn new() -> Self {
+        Self {
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<A, R, G> Default for DefaultFallbackFactory<A, R, G>
+where
+    G: ?Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, R, G> Debug for DefaultFallbackFactory<A, R, G>
+where
+    G: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name_of!(type DefaultFallbackFactory<A, R, G>))
+    }
+}
+
+/// Creates a fallback for return types that implement [`Default`].
+pub trait DefaultFallback<G>
+where
+    G: UsesStorage + ?Sized,
+{
+    /// Creates the fallback.
+    fn default_fallback(&self) -> Option<Shared<G, G>>;
+}
+
+impl<A, R, G> DefaultFallback<G> for DefaultFallbackFactory<A, R, G>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    R: Default,
+    G: ReturnValueGenerator<A, R> + FromReturnValueGenerator<return_value::DefaultValue> + ?Sized,
+{
+    fn default_fallback(&self) -> Option<Shared<G, G>> {
+        Some(G::from_return_value_generator(return_value::DefaultValue))
+    }
+}
+
+/// Creates no fallback for return types that don't implement [`Default`].
+pub trait NoDefaultFallback<G>
+where
+    G: UsesStorage + ?Sized,
+{
+    /// Returns [`None`].
+    fn default_fallback(&self) -> Option<Shared<G, G>> {
+        None
+    }
+}
+
+impl<A, R, G> NoDefaultFallback<G> for &DefaultFallbackFactory<A, R, G> where G: UsesStorage + ?Sized
+{}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+    use std::rc::Rc;
+
+    type Generator<R> = dyn ReturnValueGenerator<ArgumentsMatcherMock, R>;
+
+    struct NotDefault;
+
+    // The borrow mirrors the generated code, which relies on it to pick the right trait.
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn default_fallback_is_created_for_default_return_type() {
+        let fallback =
+            (&DefaultFallbackFactory::<ArgumentsMatcherMock, String, Generator<String>>::new())
+                .default_fallback()
+                .unwrap();
+
+        assert_eq!(String::new(), fallback.generate_return_value(ArgumentsMock));
+    }
+
+    #[test]
+    fn no_fallback_is_created_for_return_type_without_default() {
+        let fallback = (&DefaultFallbackFactory::<
+            ArgumentsMatcherMock,
+            NotDefault,
+            Generator<NotDefault>,
+        >::new())
+            .default_fallback();
+
+        assert!(fallback.is_none());
+    }
+
+    #[test]
+    fn builder_sets_fallback() {
+        let mut fallback: Option<Rc<Generator<String>>> = None;
+
+        FallbackBuilder::<'_, '_, ArgumentsMatcherMock, _, Generator<String>>::new(&mut fallback)
+            .returns(String::from("foo"));
+
+        assert_eq!(
+            String::from("foo"),
+            fallback.unwrap().generate_return_value(ArgumentsMock)
+        );
+    }
+}
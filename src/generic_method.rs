@@ -0,0 +1,504 @@
+use crate::call_history::CallHistory;
+use crate::error::{CallError, CallErrorKind, VerificationError};
+use crate::matcher::{ArgumentsMatcher, RecordArguments};
+use crate::method::Method;
+use crate::method::FOOTER;
+use crate::method_call::MethodCallBuilder;
+use crate::return_value::ReturnValueGenerator;
+use crate::storage::{Cell, SingleThreadedStorage, StorageCell, ThreadSafeStorage, UsesStorage};
+use nameof::name_of;
+use std::any::{type_name, Any, TypeId};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+/// Describes the types of a generic method for the concrete type parameters `K`.
+///
+/// `K` is either a single type parameter or a tuple of all type parameters of the method.
+pub trait MethodTypes<'mock, K> {
+    /// The arguments matcher for calls with the type parameters `K`.
+    type ArgumentsMatcher: for<'args> ArgumentsMatcher<'args>;
+    /// The return type for calls with the type parameters `K`.
+    type ReturnValue;
+    /// The type-erased return value generator stored by the method.
+    type ReturnValueGenerator: ReturnValueGenerator<Self::ArgumentsMatcher, Self::ReturnValue>
+        + UsesStorage
+        + ?Sized;
+}
+
+type MethodOf<'mock, F, K> = Method<
+    'mock,
+    <F as MethodTypes<'mock, K>>::ArgumentsMatcher,
+    <F as MethodTypes<'mock, K>>::ReturnValue,
+    <F as MethodTypes<'mock, K>>::ReturnValueGenerator,
+>;
+
+/// A [`Method`] whose concrete types are no longer known.
+/// The concrete method can be recovered using [`Any`], which requires the method to be `'static`.
+pub trait AnyMethod<'mock>: Debug {
+    /// Returns the method as [`Any`], so that it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the method as [`Any`], so that it can be downcast to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Panics if the expected calls were not satisfied.
+    fn verify_unwrap(&self);
+
+    /// Returns an error if the expected calls were not satisfied.
+    fn verification_errors(&self) -> Vec<VerificationError>;
+
+    /// Returns the calls to the method that were not expected.
+    fn call_errors(&self) -> Vec<CallError>;
+
+    /// Removes all expected calls.
+    fn clear(&mut self);
+
+    /// Configures the method to expect calls in the order they were added in.
+    fn expect_method_calls_in_order(&mut self);
+}
+
+impl<'mock, A, R, G> AnyMethod<'mock> for Method<'mock, A, R, G>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
+    Self: 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn verify_unwrap(&self) {
+        Method::verify_unwrap(self)
+    }
+
+    fn verification_errors(&self) -> Vec<VerificationError> {
+        Method::verification_errors(self)
+    }
+
+    fn call_errors(&self) -> Vec<CallError> {
+        Method::call_errors(self)
+    }
+
+    fn clear(&mut self) {
+        Method::clear(self)
+    }
+
+    fn expect_method_calls_in_order(&mut self) {
+        Method::expect_method_calls_in_order(self)
+    }
+}
+
+impl<'mock> UsesStorage for dyn AnyMethod<'mock> + 'mock {
+    type Storage = SingleThreadedStorage;
+}
+
+impl<'mock> UsesStorage for dyn AnyMethod<'mock> + Send + Sync + 'mock {
+    type Storage = ThreadSafeStorage;
+}
+
+/// Converts a [`Method`] into the type-erased method that is stored by a [`GenericMethod`].
+/// Thread-safe mocks store methods that are [`Send`] and [`Sync`], which is enforced by this conversion.
+pub trait FromMethod<M> {
+    /// Type-erases the given method.
+    fn from_method(method: M) -> Box<Self>;
+}
+
+impl<'mock, M> FromMethod<M> for dyn AnyMethod<'mock> + 'mock
+where
+    M: AnyMethod<'mock> + 'mock,
+{
+    fn from_method(method: M) -> Box<Self> {
+        Box::new(method)
+    }
+}
+
+impl<'mock, M> FromMethod<M> for dyn AnyMethod<'mock> + Send + Sync + 'mock
+where
+    M: AnyMethod<'mock> + Send + Sync + 'mock,
+{
+    fn from_method(method: M) -> Box<Self> {
+        Box::new(method)
+    }
+}
+
+/// A method with generic type parameters.
+/// Expected calls are kept separately for each combination of type parameters,
+/// which are identified by their [`TypeId`].
+///
+/// The methods for each combination of type parameters are stored type-erased and are downcast
+/// using [`Any`], which is why the mock (and therefore `'mock`) needs to be `'static`.
+#[allow(missing_docs)]
+pub struct GenericMethod<'mock, F, E = dyn AnyMethod<'mock> + 'mock>
+where
+    E: AnyMethod<'mock> + UsesStorage + ?Sized,
+{
+    name: &'static str,
+    methods: Vec<ErasedMethod<E>>,
+    calls_in_order: bool,
+    call_errors: Cell<E, Vec<CallError>>,
+    phantom_data: PhantomData<fn() -> (&'mock (), F)>,
+}
+
+/// The method for one combination of type parameters.
+/// `method` was created from a `MethodOf<'mock, F, K>` where `K` is the type identified by `type_id`.
+/// The method is looked up using `type_id`, because different type parameters
+/// may result in the same type of method, e.g. if the type parameters are not used by the arguments.
+struct ErasedMethod<E>
+where
+    E: ?Sized,
+{
+    type_id: TypeId,
+    type_name: &'static str,
+    method: Box<E>,
+    clone: fn(&E) -> Box<E>,
+}
+
+impl<E> Clone for ErasedMethod<E>
+where
+    E: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            type_id: self.type_id,
+            type_name: self.type_name,
+            method: (self.clone)(&self.method),
+            clone: self.clone,
+        }
+    }
+}
+
+impl<'mock, F, E> Debug for GenericMethod<'mock, F, E>
+where
+    E: AnyMethod<'mock> + UsesStorage + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type GenericMethod<'mock, F, E>))
+            .field(name_of!(name in Self), &self.name)
+            .field(
+                name_of!(methods in Self),
+                &self
+                    .methods
+                    .iter()
+                    .map(|erased_method| &erased_method.method)
+                    .collect::<Vec<_>>(),
+            )
+            .field(name_of!(calls_in_order in Self), &self.calls_in_order)
+            .field(name_of!(call_errors in Self), &self.own_call_errors())
+            .finish()
+    }
+}
+
+impl<'mock, F, E> Clone for GenericMethod<'mock, F, E>
+where
+    E: AnyMethod<'mock> + UsesStorage + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            methods: self.methods.clone(),
+            calls_in_order: self.calls_in_order,
+            call_errors: StorageCell::new(self.own_call_errors()),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+#[allow(missing_docs)]
+impl<'mock, F, E> GenericMethod<'mock, F, E>
+where
+    E: AnyMethod<'mock> + UsesStorage + ?Sized,
+{
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            methods: Vec::new(),
+            calls_in_order: false,
+            call_errors: StorageCell::new(Vec::new()),
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn add_expected_call<K>(
+        &mut self,
+        matcher: <F as MethodTypes<'mock, K>>::ArgumentsMatcher,
+    ) -> MethodCallBuilder<
+        'mock,
+        '_,
+        <F as MethodTypes<'mock, K>>::ArgumentsMatcher,
+        <F as MethodTypes<'mock, K>>::ReturnValue,
+        <F as MethodTypes<'mock, K>>::ReturnValueGenerator,
+    >
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+        E: FromMethod<MethodOf<'mock, F, K>>,
+    {
+        if self.method::<K>().is_none() {
+            self.add_method::<K>();
+        }
+
+        self.method_mut::<K>().unwrap().add_expected_call(matcher)
+    }
+
+    pub fn expect_method_calls_in_order(&mut self) {
+        self.calls_in_order = true;
+
+        for erased_method in &mut self.methods {
+            erased_method.method.expect_method_calls_in_order();
+        }
+    }
+
+    pub fn call_unwrap<'a, K>(
+        &'a self,
+        arguments: <<F as MethodTypes<'mock, K>>::ArgumentsMatcher as ArgumentsMatcher<'a>>::Arguments,
+    ) -> <F as MethodTypes<'mock, K>>::ReturnValue
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+    {
+        match self.method::<K>() {
+            Some(method) => method.call_unwrap(arguments),
+            None => {
+                let error = CallError::new(
+                    self.name.to_string(),
+                    arguments.to_string(),
+                    CallErrorKind::UnexpectedTypeParameters {
+                        type_parameters: type_name::<K>(),
+                        expected_type_parameters: self
+                            .methods
+                            .iter()
+                            .map(|erased_method| erased_method.type_name)
+                            .collect(),
+                    },
+                );
+                let message = format!("\n\n{}{}\n", error, FOOTER);
+                self.call_errors.with(|call_errors| call_errors.push(error));
+                panic!("{}", message)
+            }
+        }
+    }
+
+    /// Returns the calls that were made to the method with the type parameters `K`.
+    pub fn call_history<K>(
+        &self,
+    ) -> CallHistory<
+        <<F as MethodTypes<'mock, K>>::ArgumentsMatcher as RecordArguments>::RecordedArguments,
+    >
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+    {
+        match self.method::<K>() {
+            Some(method) => method.call_history(),
+            None => CallHistory::new(self.method_name::<K>(), Vec::new()),
+        }
+    }
+
+    pub fn verify_unwrap(&self) {
+        for erased_method in &self.methods {
+            erased_method.method.verify_unwrap();
+        }
+    }
+
+    pub fn verification_errors(&self) -> Vec<VerificationError> {
+        self.methods
+            .iter()
+            .flat_map(|erased_method| erased_method.method.verification_errors())
+            .collect()
+    }
+
+    /// Returns the calls to the method that were not expected, regardless of their type parameters.
+    pub fn call_errors(&self) -> Vec<CallError> {
+        let mut call_errors = self.own_call_errors();
+
+        for erased_method in &self.methods {
+            call_errors.extend(erased_method.method.call_errors());
+        }
+
+        call_errors
+    }
+
+    /// Removes all expected calls for all type parameters and all call errors.
+    pub fn clear(&mut self) {
+        self.call_errors.with(|call_errors| call_errors.clear());
+
+        for erased_method in &mut self.methods {
+            erased_method.method.clear();
+        }
+    }
+
+    fn own_call_errors(&self) -> Vec<CallError> {
+        self.call_errors.with(|call_errors| call_errors.clone())
+    }
+
+    fn method_name<K>(&self) -> String {
+        format!("{}::<{}>", self.name, type_name::<K>())
+    }
+
+    fn add_method<K>(&mut self)
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+        E: FromMethod<MethodOf<'mock, F, K>>,
+    {
+        let mut method = MethodOf::<'mock, F, K>::new(self.method_name::<K>());
+
+        if self.calls_in_order {
+            method.expect_method_calls_in_order();
+        }
+
+        self.methods.push(ErasedMethod {
+            type_id: TypeId::of::<K>(),
+            type_name: type_name::<K>(),
+            method: E::from_method(method),
+            clone: |method| E::from_method(downcast_ref::<MethodOf<'mock, F, K>>(method).clone()),
+        });
+    }
+
+    fn method<K>(&self) -> Option<&MethodOf<'mock, F, K>>
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+    {
+        let type_id = TypeId::of::<K>();
+
+        self.methods
+            .iter()
+            .find(|erased_method| erased_method.type_id == type_id)
+            .map(|erased_method| downcast_ref(&*erased_method.method))
+    }
+
+    fn method_mut<K>(&mut self) -> Option<&mut MethodOf<'mock, F, K>>
+    where
+        K: 'static,
+        F: MethodTypes<'mock, K>,
+        MethodOf<'mock, F, K>: 'static,
+    {
+        let type_id = TypeId::of::<K>();
+
+        self.methods
+            .iter_mut()
+            .find(|erased_method| erased_method.type_id == type_id)
+            .map(|erased_method| downcast_mut(&mut *erased_method.method))
+    }
+}
+
+fn downcast_ref<'mock, M>(method: &(impl AnyMethod<'mock> + ?Sized)) -> &M
+where
+    M: 'static,
+{
+    method
+        .as_any()
+        .downcast_ref()
+        .expect("The type of the method does not match its type parameters")
+}
+
+fn downcast_mut<'mock, M>(method: &mut (impl AnyMethod<'mock> + ?Sized)) -> &mut M
+where
+    M: 'static,
+{
+    method
+        .as_any_mut()
+        .downcast_mut()
+        .expect("The type of the method does not match its type parameters")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    struct TestMethodTypes;
+
+    impl<'mock, K> MethodTypes<'mock, K> for TestMethodTypes
+    where
+        K: Default + 'static,
+    {
+        type ArgumentsMatcher = ArgumentsMatcherMock;
+        type ReturnValue = K;
+        type ReturnValueGenerator = dyn ReturnValueGenerator<ArgumentsMatcherMock, K> + 'mock;
+    }
+
+    #[test]
+    fn calls_are_dispatched_by_type_parameter() {
+        let mut method = GenericMethod::<'_, TestMethodTypes>::new("test");
+
+        method
+            .add_expected_call::<u32>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(42);
+        method
+            .add_expected_call::<String>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("foo"));
+
+        assert_eq!(42, method.call_unwrap::<u32>(ArgumentsMock));
+        assert_eq!("foo", method.call_unwrap::<String>(ArgumentsMock));
+    }
+
+    #[test]
+    #[should_panic(expected = "The call test::<u64>")]
+    fn call_panics_with_unexpected_type_parameter() {
+        let mut method = GenericMethod::<'_, TestMethodTypes>::new("test");
+
+        method
+            .add_expected_call::<u32>(ArgumentsMatcherMock::new(None))
+            .times(..)
+            .returns(42);
+
+        method.call_unwrap::<u64>(ArgumentsMock);
+    }
+
+    #[test]
+    fn call_history_only_contains_calls_with_the_given_type_parameter() {
+        let mut method = GenericMethod::<'_, TestMethodTypes>::new("test");
+
+        method
+            .add_expected_call::<u32>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(42);
+        method
+            .add_expected_call::<String>(ArgumentsMatcherMock::new(None))
+            .times(..)
+            .returns(String::from("foo"));
+
+        method.call_unwrap::<u32>(ArgumentsMock);
+
+        method.call_history::<u32>().assert_called_times(1);
+        method.call_history::<String>().assert_not_called();
+        method.call_history::<u64>().assert_not_called();
+    }
+
+    #[test]
+    fn clone_keeps_expected_calls() {
+        let mut method = GenericMethod::<'_, TestMethodTypes>::new("test");
+
+        method
+            .add_expected_call::<u32>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(42);
+
+        let method = method.clone();
+
+        assert_eq!(42, method.call_unwrap::<u32>(ArgumentsMock));
+    }
+
+    #[test]
+    #[should_panic(expected = "The expected calls for test::<u32> were not satisified.")]
+    fn verify_panics_if_expectations_for_a_type_parameter_are_not_met() {
+        let mut method = GenericMethod::<'_, TestMethodTypes>::new("test");
+
+        method
+            .add_expected_call::<u32>(ArgumentsMatcherMock::new(None))
+            .times(1)
+            .returns(42);
+
+        method.verify_unwrap();
+    }
+}
@@ -3,7 +3,21 @@
 //! It may change at any time. Do not use this module directly.
 //!
 
+pub use crate::argument::ArgumentMatcherFactory;
 pub use crate::arguments::Arguments;
+pub use crate::error::{mock_result, mock_result_unwrap};
+pub use crate::fallback::{DefaultFallback, DefaultFallbackFactory, NoDefaultFallback};
 pub use crate::fmt::MaybeDebugWrapper;
-pub use crate::matcher::{ArgumentMatcher, ArgumentsMatcher};
+pub use crate::generic_method::{AnyMethod, FromMethod, GenericMethod, MethodTypes};
+pub use crate::matcher::pattern::PatternArgumentMatcher;
+pub use crate::matcher::{ArgumentMatcher, ArgumentsMatcher, RecordArguments};
 pub use crate::method::Method;
+pub use crate::recorded::{
+    FromRecordedValue, RecordClonedValue, RecordNothing, RecordOwnedReference, RecordReference,
+    RecordValue,
+};
+pub use crate::return_value::{FromReturnValueGenerator, ReturnValueGenerator};
+pub use crate::static_expectations::{with_static_expectations, StaticExpectations};
+pub use crate::storage::{
+    SingleThreadedStorage, Storage, StorageCell, ThreadSafeStorage, UsesStorage,
+};
@@ -79,6 +79,12 @@
 //! # Call Verification
 //! Mockiato automatically verifies that all expected calls were made when the mock goes out of scope.
 //! The mock panics when a method is called that was not configured, or if the parameters did not match.
+//!
+//! The expected calls can also be verified earlier using `mock.checkpoint()`, which removes them afterwards,
+//! or without panicking using `mock.verify()` (or its alias `mock.try_verify()`), which returns a [`MockError`]
+//! describing unsatisfied expected calls and unexpected calls.
+//! These methods are not generated if the trait has a method with the same name (e.g. `verify`),
+//! as they would shadow the trait's method.
 //! ```no_run
 //! use mockiato::mockable;
 //!
@@ -106,6 +112,45 @@
 //!     // <--  and panics otherwise
 //! }
 //! ```
+//!
+//! # Partial Mocks
+//! A mock created using `Mock::with_fallback(real)` forwards calls that don't match any expected call
+//! to a real implementation of the trait. Expected calls can also call through to it using `calls_through()`.
+//! ```
+//! use mockiato::mockable;
+//!
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! trait Calculator {
+//!     fn add(&self, a: i32, b: i32) -> i32;
+//!
+//!     fn multiply(&self, a: i32, b: i32) -> i32;
+//! }
+//!
+//! struct RealCalculator;
+//!
+//! impl Calculator for RealCalculator {
+//!     fn add(&self, a: i32, b: i32) -> i32 {
+//!         a + b
+//!     }
+//!
+//!     fn multiply(&self, a: i32, b: i32) -> i32 {
+//!         a * b
+//!     }
+//! }
+//!
+//! let mut calculator = CalculatorMock::with_fallback(RealCalculator);
+//!
+//! calculator
+//!     .expect_multiply(|arg| arg.any(), |arg| arg.partial_eq(0))
+//!     .returns(42);
+//!
+//! assert_eq!(42, calculator.multiply(3, 0));
+//! assert_eq!(6, calculator.multiply(3, 2));
+//! assert_eq!(5, calculator.add(3, 2));
+//! ```
 
 #![cfg_attr(rustc_is_nightly, feature(doc_cfg, specialization))]
 #![cfg_attr(rustc_is_nightly, allow(incomplete_features))]
@@ -196,6 +241,282 @@
 ///     fn flush(&mut self) -> io::Result<()>;
 /// }
 /// ```
+///
+/// ## `sync`
+/// Makes the mock [`Send`] and [`Sync`], so that it can be shared between threads,
+/// e.g. behind an `Arc<dyn Trait + Send + Sync>`.
+/// Argument matchers and return values must be [`Send`] and [`Sync`] in turn.
+///
+/// The expected calls are verified once the last reference to the mock is dropped.
+///
+/// ```
+/// use mockiato::mockable;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable(sync))]
+/// # ";
+/// # #[mockable(sync)]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Jane"))
+///     .returns(String::from("Hello Jane"));
+///
+/// let greeter: Arc<dyn Greeter + Send + Sync> = Arc::new(greeter);
+///
+/// thread::spawn(move || assert_eq!("Hello Jane", greeter.greet("Jane")))
+///     .join()
+///     .unwrap();
+/// ```
+///
+/// ## `async_trait`
+/// Mocks methods returning `Pin<Box<dyn Future<Output = T>>>` as if they were `async fn`s,
+/// so that their expected calls return a `T` instead of a boxed future.
+/// This is how `#[async_trait]` desugars `async fn`s, which is why it needs to be placed above `#[mockable]`.
+/// Without this parameter, boxed futures are returned as they are. `async fn`s don't need it.
+///
+/// The boxed future captures the mock, so it is only [`Send`] if the mock is as well.
+/// Methods returning `Pin<Box<dyn Future<Output = T> + Send>>` therefore also need `sync`.
+///
+/// ```
+/// use mockiato::mockable;
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// # const IGNORED: &str = "
+/// #[async_trait]
+/// #[cfg_attr(test, mockable(sync, async_trait))]
+/// trait UserRepository {
+///     async fn find_user_name(&self, id: u64) -> Option<String>;
+/// }
+/// # ";
+/// # #[mockable(sync, async_trait)]
+/// # trait UserRepository {
+/// #     fn find_user_name<'life0, 'async_trait>(
+/// #         &'life0 self,
+/// #         id: u64,
+/// #     ) -> Pin<Box<dyn Future<Output = Option<String>> + Send + 'async_trait>>
+/// #     where
+/// #         'life0: 'async_trait,
+/// #         Self: Sync + 'async_trait;
+/// # }
+///
+/// let mut user_repository = UserRepositoryMock::new();
+///
+/// user_repository
+///     .expect_find_user_name(|arg| arg.partial_eq(42))
+///     .times(..)
+///     .returns(Some(String::from("Jane")));
+/// ```
+///
+/// ## `associated_type`
+/// Specifies the types of the trait's associated types. Every associated type of the trait needs a type.  
+/// Methods may refer to the associated types using `Self::Item`.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = r#"
+/// #[cfg_attr(test, mockable(associated_type(Item = "u32")))]
+/// # "#;
+/// # #[mockable(associated_type(Item = "u32"))]
+/// trait Source {
+///     type Item;
+///
+///     fn next_item(&mut self) -> Option<Self::Item>;
+/// }
+///
+/// let mut source = SourceMock::new();
+///
+/// source.expect_next_item().returns(Some(42));
+///
+/// assert_eq!(Some(42), source.next_item());
+/// ```
+///
+/// ## `associated_const`
+/// Specifies the values of the trait's associated constants as literals.
+/// Constants with a default value in the trait can be omitted.
+/// The values are also available as associated constants of the mock struct.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = r#"
+/// #[cfg_attr(test, mockable(associated_const(NAME = "greeter", VERSION = 3)))]
+/// # "#;
+/// # #[mockable(associated_const(NAME = "greeter", VERSION = 3))]
+/// trait Plugin {
+///     const NAME: &'static str;
+///     const VERSION: u32;
+///
+///     fn run(&self);
+/// }
+///
+/// assert_eq!("greeter", <PluginMock as Plugin>::NAME);
+/// assert_eq!(3, PluginMock::VERSION);
+/// ```
+///
+/// ## `object_safe`
+/// Rejects methods without `self`, so that the trait is guaranteed to be object-safe.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(object_safe))]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+/// ```
+///
+/// ## `use_default`
+/// Uses the trait's default implementation of a method instead of mocking it.
+/// The method is then not part of the mock, so that no calls to it need to be expected.
+/// Without a value, the default implementation is used for all methods that have one.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable(use_default(greet_all)))]
+/// # ";
+/// # #[mockable(use_default(greet_all))]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+///
+///     fn greet_all(&self, names: &[&str]) -> Vec<String> {
+///         names.iter().map(|name| self.greet(name)).collect()
+///     }
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.any())
+///     .times(2)
+///     .returns_with(|args| format!("Hello {}", args.name));
+///
+/// assert_eq!(
+///     vec!["Hello Jane", "Hello John"],
+///     greeter.greet_all(&["Jane", "John"])
+/// );
+/// ```
+///
+/// ## `lenient`
+/// Calls that don't match any expected call return [`Default::default()`] instead of panicking.
+/// A different return value can be configured using `fallback_<method_name>`.
+/// Methods whose return type doesn't implement [`Default`] still panic unless a fallback was configured.
+/// Generic methods always panic on unexpected calls.
+/// The unexpected calls are returned by `unexpected_calls()`.
+/// Neither `unexpected_calls()` nor `allow_unexpected_calls()` is generated if the trait has a method with the same name.
+///
+/// A regular mock can be made lenient at runtime using `allow_unexpected_calls()`.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable(lenient))]
+/// # ";
+/// # #[mockable(lenient)]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+///
+///     fn greeting_count(&self) -> u32;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .fallback_greet()
+///     .returns_with(|args| format!("Hello {}", args.name));
+///
+/// assert_eq!("Hello Jane", greeter.greet("Jane"));
+/// assert_eq!(0, greeter.greeting_count());
+/// assert_eq!(2, greeter.unexpected_calls().len());
+/// ```
+///
+/// # Methods without `self`
+/// Calls to methods without `self` can't be dispatched to a mock.
+/// Their expected calls are configured on a separate struct named `<MockName>Statics`,
+/// which is registered for the current thread using `register()`.
+/// The expected calls are verified once the returned [`StaticExpectationsGuard`] goes out of scope.
+///
+/// The mock needs to be `'static` if the trait contains methods without `self`.
+/// The same applies to traits with generic methods, see below.
+/// Note that a method named `new` is shadowed by the mock's own `new` and needs to be called through the trait.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Service {
+///     fn new(name: &str) -> Self;
+///
+///     fn name(&self) -> String;
+/// }
+///
+/// let mut statics = ServiceMockStatics::new();
+///
+/// statics
+///     .expect_new(|arg| arg.partial_eq("Jane"))
+///     .returns_with(|args| {
+///         let mut service = ServiceMock::new();
+///         service.expect_name().returns(args.name.to_string());
+///         service
+///     });
+///
+/// let _statics = statics.register();
+///
+/// assert_eq!("Jane", <ServiceMock as Service>::new("Jane").name());
+/// ```
+///
+/// # Generic methods
+/// Expected calls to generic methods are configured separately for each type parameter,
+/// e.g. `expect_handle::<u32>(...)`.
+///
+/// The expected calls are looked up using [`Any`](std::any::Any), which only supports `'static` types.
+/// Type parameters of methods therefore need to be `'static`: `fn handle<T: Serialize>(&self, value: T)`
+/// is not supported and needs to be declared as `fn handle<T: Serialize + 'static>(&self, value: T)`.
+/// For the same reason, the mock needs to be `'static` if the trait contains generic methods.
+///
+/// Generic methods are also not supported by the other ways of handling calls:
+/// unexpected calls always panic, even if the mock is lenient,
+/// and calls can't be forwarded to the real implementation passed to `with_fallback`,
+/// neither as a fallback nor using `calls_through()`.
+///
+/// ```
+/// use mockiato::mockable;
+/// use std::fmt::Debug;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Handler {
+///     fn handle<T: Debug + 'static>(&self, value: T) -> bool;
+/// }
+///
+/// let mut handler = HandlerMock::new();
+///
+/// handler
+///     .expect_handle::<u32>(|arg| arg.partial_eq(42))
+///     .returns(true);
+/// handler
+///     .expect_handle::<&'static str>(|arg| arg.any())
+///     .returns(false);
+///
+/// assert!(handler.handle(42_u32));
+/// assert!(!handler.handle("foo"));
+/// ```
 pub use mockiato_codegen::mockable;
 
 #[cfg(doctest)]
@@ -203,17 +524,36 @@ pub use mockiato_codegen::mockable;
 mod test_readme {}
 
 pub use crate::argument::Argument;
+pub use crate::call_history::{CallHistory, RecordedCall};
+pub use crate::captured::Captured;
+pub use crate::error::{
+    CallError, CallErrorKind, ExpectedCallReport, MockError, VerificationError,
+};
 pub use crate::expected_calls::ExpectedCalls;
+pub use crate::fallback::FallbackBuilder;
+pub use crate::matcher::ArgumentMatcher;
 pub use crate::method_call::MethodCallBuilder;
+pub use crate::recorded::Recorded;
+pub use crate::sequence::{Sequence, SequenceError};
+pub use crate::static_expectations::StaticExpectationsGuard;
 
 mod argument;
 mod arguments;
+mod call_history;
+mod captured;
 mod default_return_value;
+mod error;
 mod expected_calls;
+mod fallback;
 mod fmt;
+mod generic_method;
 #[doc(hidden)]
 pub mod internal;
 mod matcher;
 mod method;
 mod method_call;
+mod recorded;
 mod return_value;
+mod sequence;
+mod static_expectations;
+mod storage;
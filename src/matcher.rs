@@ -2,20 +2,89 @@ use crate::arguments::Arguments;
 use std::fmt::{Debug, Display};
 
 pub(crate) mod any;
+pub(crate) mod capture;
+pub(crate) mod collection;
+pub(crate) mod combinator;
+pub(crate) mod field;
 pub(crate) mod nearly_eq;
+pub(crate) mod ordering;
 pub(crate) mod partial_eq;
+pub(crate) mod pattern;
+pub(crate) mod predicate;
+pub(crate) mod string;
 pub(crate) mod unordered_vec_eq;
 
-#[allow(missing_docs)]
+/// Decides whether an argument passed to a mocked method matches an expected call.
+///
+/// Argument matchers are created using the methods of [`Argument`](crate::Argument).
+/// Custom matchers can be used in any place that accepts one of the built-in matchers.
+/// The [`Display`] output describes the expected value and is used in the messages
+/// of failed calls.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, ArgumentMatcher};
+/// use std::fmt::{self, Display};
+///
+/// #[derive(Debug)]
+/// struct EvenArgumentMatcher;
+///
+/// impl Display for EvenArgumentMatcher {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "<even number>")
+///     }
+/// }
+///
+/// impl ArgumentMatcher<u32> for EvenArgumentMatcher {
+///     fn matches_argument(&self, input: &u32) -> bool {
+///         input % 2 == 0
+///     }
+/// }
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Counter {
+///     fn add(&self, value: u32);
+/// }
+///
+/// let mut counter = CounterMock::new();
+/// counter.expect_add(|_| EvenArgumentMatcher).returns(());
+/// counter.add(4);
+/// ```
 pub trait ArgumentMatcher<T>: Display + Debug {
+    /// Returns `true` if `input` matches the expected value.
     fn matches_argument(&self, input: &T) -> bool;
+
+    /// Called with an argument that was matched by this matcher once the mock has accepted the call.
+    /// Calls that are rejected, e.g. because another argument doesn't match, never reach this method.
+    /// Matchers that wrap other matchers forward the argument to the wrapped matchers that match it.
+    fn argument_accepted(&self, _input: &T) {}
+}
+
+/// Describes the arguments of a call that are recorded by the mock.
+/// It is separate from [`ArgumentsMatcher`] because the recorded arguments don't borrow from the call.
+#[allow(missing_docs)]
+pub trait RecordArguments {
+    type RecordedArguments: Clone;
 }
 
+/// `ImplicitBounds` should never be specified explicitly.
+/// It restricts `for<'args> ArgumentsMatcher<'args>` to the lifetimes that are outlived by `Self`.
+/// This allows the arguments to contain references to generic types of the mocked trait
+/// without requiring them to be `'static`.
 #[allow(missing_docs)]
-pub trait ArgumentsMatcher<'args>: Display + Debug {
+pub trait ArgumentsMatcher<'args, ImplicitBounds = &'args Self>:
+    RecordArguments + Display + Debug
+{
     type Arguments: Arguments;
 
     fn matches_arguments(&self, input: &Self::Arguments) -> bool;
+
+    fn arguments_accepted(&self, _input: &Self::Arguments) {}
+
+    fn record_arguments(input: &Self::Arguments) -> Self::RecordedArguments;
 }
 
 #[cfg(test)]
@@ -23,7 +92,7 @@ pub(crate) use self::mock::*;
 
 #[cfg(test)]
 mod mock {
-    use super::ArgumentsMatcher;
+    use super::{ArgumentsMatcher, RecordArguments};
     use crate::arguments::ArgumentsMock;
     use std::cell::RefCell;
 
@@ -51,6 +120,12 @@ mod mock {
             *self.matches_arguments_was_called.borrow_mut() = true;
             self.matches_arguments_return.unwrap()
         }
+
+        fn record_arguments(_input: &Self::Arguments) -> Self::RecordedArguments {}
+    }
+
+    impl RecordArguments for ArgumentsMatcherMock {
+        type RecordedArguments = ();
     }
 
     impl Display for ArgumentsMatcherMock {
@@ -37,7 +37,7 @@ impl Display for AnyArgumentMatcher {
     }
 }
 
-impl<'args, U> ArgumentMatcher<U> for AnyArgumentMatcher {
+impl<U> ArgumentMatcher<U> for AnyArgumentMatcher {
     fn matches_argument(&self, _input: &U) -> bool {
         true
     }
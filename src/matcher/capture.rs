@@ -0,0 +1,211 @@
+use super::any::AnyArgumentMatcher;
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use crate::captured::Captured;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches any value and stores a clone of it in `slot`.
+    ///
+    /// Arguments are only captured once the mock has accepted the call.
+    /// Calls that are rejected, e.g. because another argument doesn't match,
+    /// leave the slot untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::{mockable, Captured};
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait TokenStore {
+    ///     fn store(&self, token: &str);
+    /// }
+    ///
+    /// let token = Captured::new();
+    /// let mut store = TokenStoreMock::new();
+    /// store.expect_store(|arg| arg.capture(&token)).returns(());
+    ///
+    /// store.store("secret");
+    ///
+    /// assert_eq!(Some(String::from("secret")), token.last());
+    /// ```
+    pub fn capture<T>(&self, slot: &Captured<T>) -> CaptureArgumentMatcher<T, AnyArgumentMatcher> {
+        self.capture_matching(slot, AnyArgumentMatcher)
+    }
+
+    /// Creates an argument matcher that matches values using `matcher`
+    /// and stores a clone of every matched value of an accepted call in `slot`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::{mockable, Captured};
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Scheduler {
+    ///     fn schedule(&self, callback_id: u32);
+    /// }
+    ///
+    /// let callback_ids = Captured::new();
+    /// let mut scheduler = SchedulerMock::new();
+    /// scheduler
+    ///     .expect_schedule(|arg| arg.capture_matching(&callback_ids, arg.greater_than(10)))
+    ///     .times(2)
+    ///     .returns(());
+    ///
+    /// scheduler.schedule(11);
+    /// scheduler.schedule(12);
+    ///
+    /// assert_eq!(vec![11, 12], callback_ids.all());
+    /// ```
+    pub fn capture_matching<T, M>(
+        &self,
+        slot: &Captured<T>,
+        matcher: M,
+    ) -> CaptureArgumentMatcher<T, M> {
+        CaptureArgumentMatcher {
+            slot: slot.clone(),
+            matcher,
+        }
+    }
+}
+
+/// Converts a matched argument into the value stored in a [`Captured`] slot.
+pub trait CaptureArgument<T> {
+    /// Creates the value that is captured
+    fn capture_argument(&self) -> T;
+}
+
+impl<T> CaptureArgument<T> for T
+where
+    T: Clone,
+{
+    fn capture_argument(&self) -> T {
+        self.clone()
+    }
+}
+
+impl<T> CaptureArgument<T> for &T
+where
+    T: Clone,
+{
+    fn capture_argument(&self) -> T {
+        (*self).clone()
+    }
+}
+
+impl<T> CaptureArgument<T> for &mut T
+where
+    T: Clone,
+{
+    fn capture_argument(&self) -> T {
+        (**self).clone()
+    }
+}
+
+impl CaptureArgument<String> for &str {
+    fn capture_argument(&self) -> String {
+        (*self).to_string()
+    }
+}
+
+impl<T> CaptureArgument<Vec<T>> for &[T]
+where
+    T: Clone,
+{
+    fn capture_argument(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+pub struct CaptureArgumentMatcher<T, M> {
+    slot: Captured<T>,
+    matcher: M,
+}
+
+impl<T, M> Display for CaptureArgumentMatcher<T, M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "capture({})", self.matcher)
+    }
+}
+
+impl<T, M> Debug for CaptureArgumentMatcher<T, M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type CaptureArgumentMatcher<T, M>))
+            .field(name_of!(matcher in Self), &self.matcher)
+            .finish()
+    }
+}
+
+impl<T, M, U> ArgumentMatcher<U> for CaptureArgumentMatcher<T, M>
+where
+    M: ArgumentMatcher<U>,
+    U: CaptureArgument<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.matcher.matches_argument(input)
+    }
+
+    fn argument_accepted(&self, input: &U) {
+        self.matcher.argument_accepted(input);
+        self.slot.push(input.capture_argument());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn captures_accepted_values() {
+        let factory = Argument::internal_new();
+        let slot = Captured::new();
+        let matcher = factory.capture(&slot);
+
+        matcher.argument_accepted(&1);
+        matcher.argument_accepted(&2);
+        assert_eq!(vec![1, 2], slot.all());
+    }
+
+    #[test]
+    fn does_not_capture_values_when_matching() {
+        let factory = Argument::internal_new();
+        let slot: Captured<u32> = Captured::new();
+        let matcher = factory.capture_matching(&slot, factory.greater_than(1));
+
+        assert!(!matcher.matches_argument(&1));
+        assert!(matcher.matches_argument(&2));
+        assert!(slot.all().is_empty());
+    }
+
+    #[test]
+    fn captures_references_as_owned_values() {
+        let factory = Argument::internal_new();
+        let strings: Captured<String> = Captured::new();
+        let vecs: Captured<Vec<u32>> = Captured::new();
+
+        factory.capture(&strings).argument_accepted(&"foo");
+        factory.capture(&vecs).argument_accepted(&&[1, 2][..]);
+        assert_eq!(String::from("foo"), strings.single());
+        assert_eq!(vec![1, 2], vecs.single());
+    }
+
+    #[test]
+    fn display_shows_inner_matcher() {
+        let factory = Argument::internal_new();
+        let slot: Captured<u32> = Captured::new();
+
+        assert_eq!("capture(*)", factory.capture(&slot).to_string());
+    }
+}
@@ -0,0 +1,513 @@
+use super::combinator::{ArgumentMatcherList, ArgumentMatchers};
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use crate::fmt::{MaybeDebug, MaybeDebugWrapper};
+use nameof::name_of;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches collections containing an element
+    /// that is equal to `element` using [`PartialEq`].
+    ///
+    /// Works with slices, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`]
+    /// and the keys of a [`HashMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, recipients: &[&str]);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| arg.contains_element("Jane"))
+    ///     .returns(());
+    /// sender.send_message(&["John", "Jane"]);
+    /// ```
+    pub fn contains_element<T>(&self, element: T) -> ContainsElementArgumentMatcher<T> {
+        ContainsElementArgumentMatcher { element }
+    }
+
+    /// Creates an argument matcher that matches collections with exactly `len` elements.
+    ///
+    /// Works with slices, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`]
+    /// and [`HashMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, recipients: Vec<String>);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender.expect_send_message(|arg| arg.has_len(2)).returns(());
+    /// sender.send_message(vec![String::from("John"), String::from("Jane")]);
+    /// ```
+    pub fn has_len(&self, len: usize) -> HasLenArgumentMatcher {
+        HasLenArgumentMatcher { len }
+    }
+
+    /// Creates an argument matcher that matches empty collections.
+    ///
+    /// Works with slices, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`]
+    /// and [`HashMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, recipients: &[&str]);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender.expect_send_message(|arg| arg.is_empty()).returns(());
+    /// sender.send_message(&[]);
+    /// ```
+    pub fn is_empty(&self) -> HasLenArgumentMatcher {
+        self.has_len(0)
+    }
+
+    /// Creates an argument matcher that matches collections whose elements
+    /// are all matched by `matcher`.
+    ///
+    /// Works with slices, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`]
+    /// and the keys of a [`HashMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, recipients: &[&str]);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| arg.each(arg.ends_with("@example.com")))
+    ///     .returns(());
+    /// sender.send_message(&["jane@example.com", "john@example.com"]);
+    /// ```
+    pub fn each<M>(&self, matcher: M) -> EachArgumentMatcher<M> {
+        EachArgumentMatcher { matcher }
+    }
+
+    /// Creates an argument matcher that matches collections with one element per matcher,
+    /// where every element is matched by the matcher at the same position.
+    ///
+    /// The matchers are passed as a tuple, an array or a [`Vec`].
+    /// Works with slices, [`Vec`] and [`VecDeque`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, recipients: &[&str]);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| {
+    ///         arg.elements_in_order((arg.partial_eq("Jane"), arg.starts_with("J")))
+    ///     })
+    ///     .returns(());
+    /// sender.send_message(&["Jane", "John"]);
+    /// ```
+    pub fn elements_in_order<M>(&self, matchers: M) -> ElementsInOrderArgumentMatcher<M> {
+        ElementsInOrderArgumentMatcher { matchers }
+    }
+}
+
+/// A collection that can be matched by the collection matchers,
+/// such as [`Argument::contains_element`].
+///
+/// The list of collections is closed: this trait is not exported and is only implemented for
+/// slices, arrays, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`], [`HashMap`] and references to them.
+/// There is no implementation for every `&C` where `&C: IntoIterator`, as it would overlap with
+/// the implementation for references and match the entries of a [`HashMap`] instead of its keys.
+pub trait ArgumentCollection {
+    /// The type of the elements
+    type Element;
+
+    /// Returns references to all elements of the collection.
+    fn elements(&self) -> Vec<&Self::Element>;
+
+    /// Returns the number of elements in the collection.
+    fn len(&self) -> usize;
+}
+
+/// A collection whose elements have a well-defined order.
+pub trait OrderedArgumentCollection: ArgumentCollection {}
+
+impl<T> ArgumentCollection for [T] {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+impl<T> OrderedArgumentCollection for [T] {}
+
+impl<T, const N: usize> ArgumentCollection for [T; N] {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> OrderedArgumentCollection for [T; N] {}
+
+impl<T> ArgumentCollection for Vec<T> {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<T> OrderedArgumentCollection for Vec<T> {}
+
+impl<T> ArgumentCollection for VecDeque<T> {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+impl<T> OrderedArgumentCollection for VecDeque<T> {}
+
+impl<T, S> ArgumentCollection for HashSet<T, S> {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+}
+
+impl<T> ArgumentCollection for BTreeSet<T> {
+    type Element = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+}
+
+impl<K, V, S> ArgumentCollection for HashMap<K, V, S> {
+    type Element = K;
+
+    fn elements(&self) -> Vec<&K> {
+        self.keys().collect()
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+impl<C> ArgumentCollection for &C
+where
+    C: ArgumentCollection + ?Sized,
+{
+    type Element = C::Element;
+
+    fn elements(&self) -> Vec<&Self::Element> {
+        (**self).elements()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<C> OrderedArgumentCollection for &C where C: OrderedArgumentCollection + ?Sized {}
+
+impl<C> ArgumentCollection for &mut C
+where
+    C: ArgumentCollection + ?Sized,
+{
+    type Element = C::Element;
+
+    fn elements(&self) -> Vec<&Self::Element> {
+        (**self).elements()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<C> OrderedArgumentCollection for &mut C where C: OrderedArgumentCollection + ?Sized {}
+
+pub struct ContainsElementArgumentMatcher<T> {
+    element: T,
+}
+
+impl<T> Display for ContainsElementArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "contains_element({:?})",
+            MaybeDebugWrapper(&self.element)
+        )
+    }
+}
+
+impl<T> Debug for ContainsElementArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type ContainsElementArgumentMatcher<T>))
+            .field(name_of!(element in Self), &MaybeDebugWrapper(&self.element))
+            .finish()
+    }
+}
+
+impl<T, C> ArgumentMatcher<C> for ContainsElementArgumentMatcher<T>
+where
+    T: PartialEq<C::Element> + MaybeDebug,
+    C: ArgumentCollection,
+{
+    fn matches_argument(&self, input: &C) -> bool {
+        input
+            .elements()
+            .into_iter()
+            .any(|element| self.element == *element)
+    }
+}
+
+#[derive(Debug)]
+pub struct HasLenArgumentMatcher {
+    len: usize,
+}
+
+impl Display for HasLenArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "has_len({})", self.len)
+    }
+}
+
+impl<C> ArgumentMatcher<C> for HasLenArgumentMatcher
+where
+    C: ArgumentCollection,
+{
+    fn matches_argument(&self, input: &C) -> bool {
+        input.len() == self.len
+    }
+}
+
+#[derive(Debug)]
+pub struct EachArgumentMatcher<M> {
+    matcher: M,
+}
+
+impl<M> Display for EachArgumentMatcher<M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "each({})", self.matcher)
+    }
+}
+
+impl<M, C> ArgumentMatcher<C> for EachArgumentMatcher<M>
+where
+    M: ArgumentMatcher<C::Element>,
+    C: ArgumentCollection,
+{
+    fn matches_argument(&self, input: &C) -> bool {
+        input
+            .elements()
+            .into_iter()
+            .all(|element| self.matcher.matches_argument(element))
+    }
+
+    fn argument_accepted(&self, input: &C) {
+        for element in input.elements() {
+            self.matcher.argument_accepted(element);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ElementsInOrderArgumentMatcher<M> {
+    matchers: M,
+}
+
+impl<M> Display for ElementsInOrderArgumentMatcher<M>
+where
+    M: ArgumentMatcherList,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        self.matchers.fmt_separated(f, ", ")?;
+        write!(f, "]")
+    }
+}
+
+impl<M, C> ArgumentMatcher<C> for ElementsInOrderArgumentMatcher<M>
+where
+    M: ArgumentMatchers<C::Element>,
+    C: OrderedArgumentCollection,
+{
+    fn matches_argument(&self, input: &C) -> bool {
+        self.matchers.match_in_order(&input.elements())
+    }
+
+    fn argument_accepted(&self, input: &C) {
+        self.matchers.accepted_in_order(&input.elements())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn contains_element_matches_collections_containing_element() {
+        let factory = Argument::internal_new();
+        let matcher = factory.contains_element(2);
+
+        assert!(matcher.matches_argument(&vec![1, 2, 3]));
+        assert!(matcher.matches_argument(&&[2][..]));
+        assert!(matcher.matches_argument(&VecDeque::from(vec![2])));
+        assert!(matcher.matches_argument(&[2].iter().copied().collect::<BTreeSet<_>>()));
+        assert!(!matcher.matches_argument(&vec![1, 3]));
+    }
+
+    #[test]
+    fn contains_element_matches_keys_of_hash_map() {
+        let factory = Argument::internal_new();
+        let map: HashMap<_, _> = vec![("foo", 1)].into_iter().collect();
+
+        assert!(factory.contains_element("foo").matches_argument(&map));
+        assert!(!factory.contains_element("bar").matches_argument(&map));
+    }
+
+    #[test]
+    fn has_len_matches_length() {
+        let factory = Argument::internal_new();
+        let set: HashSet<_> = vec![1, 2].into_iter().collect();
+
+        assert!(factory.has_len(2).matches_argument(&set));
+        assert!(!factory.has_len(1).matches_argument(&set));
+        assert!(factory.is_empty().matches_argument(&Vec::<u8>::new()));
+        assert!(!factory.is_empty().matches_argument(&set));
+    }
+
+    #[test]
+    fn has_len_matches_length_of_arrays_and_references() {
+        let factory = Argument::internal_new();
+        let map: HashMap<_, _> = vec![("foo", 1)].into_iter().collect();
+
+        assert!(factory.has_len(3).matches_argument(&[1, 2, 3]));
+        assert!(factory.has_len(1).matches_argument(&&[1][..]));
+        assert!(factory.has_len(1).matches_argument(&&map));
+        assert!(!factory.has_len(1).matches_argument(&&mut vec![1, 2]));
+    }
+
+    #[test]
+    fn each_matches_if_all_elements_match() {
+        let factory = Argument::internal_new();
+        let matcher = factory.each(factory.greater_than(0));
+
+        assert!(matcher.matches_argument(&vec![1, 2]));
+        assert!(matcher.matches_argument(&Vec::new()));
+        assert!(!matcher.matches_argument(&vec![1, 0]));
+    }
+
+    #[test]
+    fn elements_in_order_matches_elements_at_same_position() {
+        let factory = Argument::internal_new();
+        let matcher = factory.elements_in_order([factory.partial_eq(1), factory.partial_eq(2)]);
+
+        assert!(matcher.matches_argument(&vec![1, 2]));
+        assert!(!matcher.matches_argument(&vec![2, 1]));
+        assert!(!matcher.matches_argument(&vec![1, 2, 3]));
+        assert!(!matcher.matches_argument(&vec![1]));
+    }
+
+    #[test]
+    fn elements_in_order_accepts_different_matchers() {
+        let factory = Argument::internal_new();
+        let matcher =
+            factory.elements_in_order((factory.partial_eq("foo"), factory.starts_with("b")));
+
+        assert!(matcher.matches_argument(&vec!["foo", "bar"]));
+        assert!(!matcher.matches_argument(&vec!["foo", "foo"]));
+    }
+
+    #[test]
+    fn matchers_have_display_output() {
+        let factory = Argument::internal_new();
+
+        assert_eq!("has_len(2)", factory.has_len(2).to_string());
+        assert_eq!("has_len(0)", factory.is_empty().to_string());
+        assert_eq!(
+            r#"each(contains("a"))"#,
+            factory.each(factory.contains("a")).to_string()
+        );
+        assert_eq!(
+            "[*, *]",
+            factory
+                .elements_in_order((factory.any(), factory.any()))
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches values that are not matched by `matcher`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, message: &str);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| arg.not(arg.partial_eq("Don't make lemonade")))
+    ///     .returns(());
+    /// sender.send_message("Make lemonade");
+    /// ```
+    pub fn not<M>(&self, matcher: M) -> NotArgumentMatcher<M> {
+        NotArgumentMatcher { matcher }
+    }
+
+    /// Creates an argument matcher that matches values that are matched by all of the `matchers`.
+    ///
+    /// The matchers are passed either as a tuple, which allows combining matchers
+    /// of different types, or as a [`Vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, message: &str);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| {
+    ///         arg.all_of((
+    ///             arg.not(arg.partial_eq("foo")),
+    ///             arg.not(arg.partial_eq("bar")),
+    ///         ))
+    ///     })
+    ///     .returns(());
+    /// sender.send_message("baz");
+    /// ```
+    pub fn all_of<M>(&self, matchers: M) -> AllOfArgumentMatcher<M> {
+        AllOfArgumentMatcher { matchers }
+    }
+
+    /// Creates an argument matcher that matches values that are matched by any of the `matchers`.
+    ///
+    /// The matchers are passed either as a tuple, which allows combining matchers
+    /// of different types, or as a [`Vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, message: &str);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| arg.any_of((arg.partial_eq("foo"), arg.partial_eq("bar"))))
+    ///     .times(2)
+    ///     .returns(());
+    /// sender.send_message("foo");
+    /// sender.send_message("bar");
+    /// ```
+    pub fn any_of<M>(&self, matchers: M) -> AnyOfArgumentMatcher<M> {
+        AnyOfArgumentMatcher { matchers }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotArgumentMatcher<M> {
+    matcher: M,
+}
+
+impl<M> Display for NotArgumentMatcher<M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not({})", self.matcher)
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for NotArgumentMatcher<M>
+where
+    M: ArgumentMatcher<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        !self.matcher.matches_argument(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct AllOfArgumentMatcher<M> {
+    matchers: M,
+}
+
+impl<M> Display for AllOfArgumentMatcher<M>
+where
+    M: ArgumentMatcherList,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        self.matchers.fmt_separated(f, " & ")?;
+        write!(f, ")")
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for AllOfArgumentMatcher<M>
+where
+    M: ArgumentMatchers<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        self.matchers.all_match(input)
+    }
+
+    fn argument_accepted(&self, input: &T) {
+        self.matchers.accepted(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct AnyOfArgumentMatcher<M> {
+    matchers: M,
+}
+
+impl<M> Display for AnyOfArgumentMatcher<M>
+where
+    M: ArgumentMatcherList,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        self.matchers.fmt_separated(f, " | ")?;
+        write!(f, ")")
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for AnyOfArgumentMatcher<M>
+where
+    M: ArgumentMatchers<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        self.matchers.any_matches(input)
+    }
+
+    fn argument_accepted(&self, input: &T) {
+        self.matchers.accepted(input)
+    }
+}
+
+/// A list of argument matchers, either a tuple, an array or a [`Vec`].
+pub trait ArgumentMatcherList: Debug {
+    /// Formats the matchers separated by `separator`.
+    fn fmt_separated(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result;
+}
+
+/// A list of argument matchers that all match arguments of type `T`.
+pub trait ArgumentMatchers<T>: ArgumentMatcherList {
+    /// Returns `true` if all matchers match `input`.
+    fn all_match(&self, input: &T) -> bool;
+
+    /// Returns `true` if any of the matchers matches `input`.
+    fn any_matches(&self, input: &T) -> bool;
+
+    /// Returns `true` if there are as many `inputs` as matchers
+    /// and each matcher matches the input at the same position.
+    fn match_in_order(&self, inputs: &[&T]) -> bool;
+
+    /// Forwards an accepted `input` to the matchers that match it.
+    fn accepted(&self, input: &T);
+
+    /// Forwards accepted `inputs` to the matchers at the same position.
+    fn accepted_in_order(&self, inputs: &[&T]);
+}
+
+impl<M> ArgumentMatcherList for Vec<M>
+where
+    M: Display + Debug,
+{
+    fn fmt_separated(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+        self.as_slice().fmt_separated(f, separator)
+    }
+}
+
+impl<T, M> ArgumentMatchers<T> for Vec<M>
+where
+    M: ArgumentMatcher<T>,
+{
+    fn all_match(&self, input: &T) -> bool {
+        self.as_slice().all_match(input)
+    }
+
+    fn any_matches(&self, input: &T) -> bool {
+        self.as_slice().any_matches(input)
+    }
+
+    fn match_in_order(&self, inputs: &[&T]) -> bool {
+        self.as_slice().match_in_order(inputs)
+    }
+
+    fn accepted(&self, input: &T) {
+        self.as_slice().accepted(input)
+    }
+
+    fn accepted_in_order(&self, inputs: &[&T]) {
+        self.as_slice().accepted_in_order(inputs)
+    }
+}
+
+impl<M, const N: usize> ArgumentMatcherList for [M; N]
+where
+    M: Display + Debug,
+{
+    fn fmt_separated(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+        self.as_slice().fmt_separated(f, separator)
+    }
+}
+
+impl<T, M, const N: usize> ArgumentMatchers<T> for [M; N]
+where
+    M: ArgumentMatcher<T>,
+{
+    fn all_match(&self, input: &T) -> bool {
+        self.as_slice().all_match(input)
+    }
+
+    fn any_matches(&self, input: &T) -> bool {
+        self.as_slice().any_matches(input)
+    }
+
+    fn match_in_order(&self, inputs: &[&T]) -> bool {
+        self.as_slice().match_in_order(inputs)
+    }
+
+    fn accepted(&self, input: &T) {
+        self.as_slice().accepted(input)
+    }
+
+    fn accepted_in_order(&self, inputs: &[&T]) {
+        self.as_slice().accepted_in_order(inputs)
+    }
+}
+
+impl<M> ArgumentMatcherList for [M]
+where
+    M: Display + Debug,
+{
+    fn fmt_separated(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+        fmt_separated(
+            f,
+            separator,
+            self.iter().map(|matcher| matcher as &dyn Display),
+        )
+    }
+}
+
+impl<T, M> ArgumentMatchers<T> for [M]
+where
+    M: ArgumentMatcher<T>,
+{
+    fn all_match(&self, input: &T) -> bool {
+        self.iter().all(|matcher| matcher.matches_argument(input))
+    }
+
+    fn any_matches(&self, input: &T) -> bool {
+        self.iter().any(|matcher| matcher.matches_argument(input))
+    }
+
+    fn match_in_order(&self, inputs: &[&T]) -> bool {
+        self.len() == inputs.len()
+            && self
+                .iter()
+                .zip(inputs)
+                .all(|(matcher, input)| matcher.matches_argument(input))
+    }
+
+    fn accepted(&self, input: &T) {
+        for matcher in self {
+            if matcher.matches_argument(input) {
+                matcher.argument_accepted(input);
+            }
+        }
+    }
+
+    fn accepted_in_order(&self, inputs: &[&T]) {
+        for (matcher, input) in self.iter().zip(inputs) {
+            matcher.argument_accepted(input);
+        }
+    }
+}
+
+macro_rules! impl_argument_matchers_for_tuple {
+    ($($matcher:ident: $index:tt),+) => {
+        impl<$($matcher),+> ArgumentMatcherList for ($($matcher,)+)
+        where
+            $($matcher: Display + Debug,)+
+        {
+            fn fmt_separated(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+                fmt_separated(f, separator, vec![$(&self.$index as &dyn Display),+])
+            }
+        }
+
+        impl<T, $($matcher),+> ArgumentMatchers<T> for ($($matcher,)+)
+        where
+            $($matcher: ArgumentMatcher<T>,)+
+        {
+            fn all_match(&self, input: &T) -> bool {
+                $(self.$index.matches_argument(input))&&+
+            }
+
+            fn any_matches(&self, input: &T) -> bool {
+                $(self.$index.matches_argument(input))||+
+            }
+
+            fn match_in_order(&self, inputs: &[&T]) -> bool {
+                inputs.len() == [$($index),+].len()
+                    && $(self.$index.matches_argument(inputs[$index]))&&+
+            }
+
+            fn accepted(&self, input: &T) {
+                $(
+                    if self.$index.matches_argument(input) {
+                        self.$index.argument_accepted(input);
+                    }
+                )+
+            }
+
+            fn accepted_in_order(&self, inputs: &[&T]) {
+                $(self.$index.argument_accepted(inputs[$index]);)+
+            }
+        }
+    };
+}
+
+impl_argument_matchers_for_tuple!(M0: 0);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2, M3: 3);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2, M3: 3, M4: 4);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2, M3: 3, M4: 4, M5: 5);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2, M3: 3, M4: 4, M5: 5, M6: 6);
+impl_argument_matchers_for_tuple!(M0: 0, M1: 1, M2: 2, M3: 3, M4: 4, M5: 5, M6: 6, M7: 7);
+
+fn fmt_separated<'a>(
+    f: &mut fmt::Formatter<'_>,
+    separator: &str,
+    matchers: impl IntoIterator<Item = &'a dyn Display>,
+) -> fmt::Result {
+    for (index, matcher) in matchers.into_iter().enumerate() {
+        if index > 0 {
+            write!(f, "{}", separator)?;
+        }
+
+        write!(f, "{}", matcher)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matcher::any::AnyArgumentMatcher;
+
+    #[test]
+    fn not_inverts_matcher() {
+        let factory = Argument::internal_new();
+        let matcher = factory.not(factory.partial_eq(3));
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&3));
+    }
+
+    #[test]
+    fn all_of_matches_if_all_matchers_match() {
+        let factory = Argument::internal_new();
+        let matcher = factory.all_of((
+            factory.matches(|value: &u32| *value > 3),
+            factory.not(factory.partial_eq(5)),
+        ));
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&5));
+        assert!(!matcher.matches_argument(&2));
+    }
+
+    #[test]
+    fn any_of_matches_if_any_matcher_matches() {
+        let factory = Argument::internal_new();
+        let matcher = factory.any_of(vec![factory.partial_eq(1), factory.partial_eq(2)]);
+
+        assert!(matcher.matches_argument(&1));
+        assert!(matcher.matches_argument(&2));
+        assert!(!matcher.matches_argument(&3));
+    }
+
+    #[test]
+    fn empty_vec_is_matched_by_all_of_but_not_by_any_of() {
+        let factory = Argument::internal_new();
+        let matchers: Vec<AnyArgumentMatcher> = Vec::new();
+
+        assert!(factory.all_of(matchers).matches_argument(&1));
+        assert!(!factory
+            .any_of(Vec::<AnyArgumentMatcher>::new())
+            .matches_argument(&1));
+    }
+
+    #[test]
+    fn combinators_have_display_output() {
+        let factory = Argument::internal_new();
+        let greater_than_three = || {
+            factory
+                .matches(|value: &u32| *value > 3)
+                .described_as("> 3")
+        };
+
+        assert_eq!("not(*)", factory.not(factory.any()).to_string());
+        assert_eq!(
+            "(> 3 | *)",
+            factory
+                .any_of((greater_than_three(), factory.any()))
+                .to_string()
+        );
+        assert_eq!(
+            "(> 3 & not(> 3))",
+            factory
+                .all_of((greater_than_three(), factory.not(greater_than_three())))
+                .to_string()
+        );
+    }
+}
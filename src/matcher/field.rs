@@ -0,0 +1,200 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+
+impl Argument {
+    /// Creates an argument matcher that matches a part of the argument,
+    /// e.g. a single field of a struct.
+    ///
+    /// `projection` selects the part of the argument, which is then matched by the matcher
+    /// created by `matcher`. The type of the projection's parameter needs to be annotated.
+    /// The argument can be either that type or a reference to it.
+    ///
+    /// Failed calls show the matcher as `<field>: <matcher>`, unless a label
+    /// is provided using `labelled`. Field matchers can be nested to match deeper paths.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// #[derive(Debug)]
+    /// struct Request {
+    ///     user_id: u32,
+    ///     body: String,
+    /// }
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Client {
+    ///     fn send(&self, request: &Request);
+    /// }
+    ///
+    /// # fn main() {
+    /// let mut client = ClientMock::new();
+    /// client
+    ///     .expect_send(|arg| {
+    ///         arg.field(|request: &Request| &request.user_id, |arg| arg.partial_eq(42))
+    ///             .labelled("user_id")
+    ///     })
+    ///     .returns(());
+    /// client.send(&Request {
+    ///     user_id: 42,
+    ///     body: String::from("Hello"),
+    /// });
+    /// # }
+    /// ```
+    pub fn field<T, V, P, F, M>(
+        &self,
+        projection: P,
+        matcher: F,
+    ) -> FieldArgumentMatcher<T, V, P, M>
+    where
+        P: Fn(&T) -> &V,
+        F: FnOnce(&Argument) -> M,
+        M: ArgumentMatcher<V>,
+    {
+        FieldArgumentMatcher {
+            projection,
+            matcher: matcher(self),
+            label: None,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Matches a part of the argument selected by a projection.
+/// Created using [`Argument::field`].
+pub struct FieldArgumentMatcher<T, V, P, M> {
+    projection: P,
+    matcher: M,
+    label: Option<String>,
+    phantom_data: PhantomData<fn(&T) -> &V>,
+}
+
+impl<T, V, P, M> FieldArgumentMatcher<T, V, P, M> {
+    /// Names the part of the argument selected by the projection.
+    /// The label is shown in the messages of failed calls.
+    pub fn labelled(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl<T, V, P, M> Display for FieldArgumentMatcher<T, V, P, M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(ref label) => write!(f, "{}: {}", label, self.matcher),
+            None => write!(f, "<field>: {}", self.matcher),
+        }
+    }
+}
+
+impl<T, V, P, M> Debug for FieldArgumentMatcher<T, V, P, M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type FieldArgumentMatcher<T, V, P, M>))
+            .field(name_of!(matcher in Self), &self.matcher)
+            .field(name_of!(label in Self), &self.label)
+            .finish()
+    }
+}
+
+impl<T, V, P, M, U> ArgumentMatcher<U> for FieldArgumentMatcher<T, V, P, M>
+where
+    P: Fn(&T) -> &V,
+    M: ArgumentMatcher<V>,
+    U: Borrow<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.matcher
+            .matches_argument((self.projection)(input.borrow()))
+    }
+
+    fn argument_accepted(&self, input: &U) {
+        self.matcher
+            .argument_accepted((self.projection)(input.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Request {
+        user: User,
+        body: String,
+    }
+
+    struct User {
+        id: u32,
+    }
+
+    fn request(id: u32, body: &str) -> Request {
+        Request {
+            user: User { id },
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_projected_field() {
+        let factory = Argument::internal_new();
+        let matcher = factory.field(
+            |request: &Request| &request.body,
+            |arg| arg.starts_with("Hi"),
+        );
+
+        assert!(matcher.matches_argument(&request(1, "Hi there")));
+        assert!(!matcher.matches_argument(&request(1, "Bye")));
+    }
+
+    #[test]
+    fn matches_references() {
+        let factory = Argument::internal_new();
+        let matcher = factory.field(
+            |request: &Request| &request.body,
+            |arg| arg.starts_with("Hi"),
+        );
+
+        assert!(matcher.matches_argument(&&request(1, "Hi there")));
+    }
+
+    #[test]
+    fn field_matchers_can_be_nested() {
+        let factory = Argument::internal_new();
+        let matcher = factory
+            .field(
+                |request: &Request| &request.user,
+                |arg| {
+                    arg.field(
+                        |user: &User| &user.id,
+                        |arg| arg.matches(|id: &u32| *id > 3).described_as("> 3"),
+                    )
+                    .labelled("id")
+                },
+            )
+            .labelled("user");
+
+        assert!(matcher.matches_argument(&request(4, "")));
+        assert!(!matcher.matches_argument(&request(3, "")));
+        assert_eq!(r#"user: id: > 3"#, matcher.to_string());
+    }
+
+    #[test]
+    fn display_shows_placeholder_without_label() {
+        let factory = Argument::internal_new();
+        let matcher = factory.field(|request: &Request| &request.body, |arg| arg.any());
+
+        assert_eq!("<field>: *", matcher.to_string());
+    }
+}
@@ -0,0 +1,305 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use crate::fmt::{MaybeDebug, MaybeDebugWrapper};
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+impl Argument {
+    /// Creates an argument matcher that matches values greater than `value` using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: u8);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.greater_than(18))
+    ///     .returns(());
+    /// thermostat.set_temperature(21);
+    /// ```
+    pub fn greater_than<T>(&self, value: T) -> GreaterThanArgumentMatcher<T> {
+        GreaterThanArgumentMatcher { value }
+    }
+
+    /// Creates an argument matcher that matches values less than `value` using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: u8);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.less_than(25))
+    ///     .returns(());
+    /// thermostat.set_temperature(21);
+    /// ```
+    pub fn less_than<T>(&self, value: T) -> LessThanArgumentMatcher<T> {
+        LessThanArgumentMatcher { value }
+    }
+
+    /// Creates an argument matcher that matches values between `lower` and `upper`
+    /// (both inclusive) using [`PartialOrd`].
+    ///
+    /// This is the same as `in_range(lower..=upper)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: u8);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.between(18, 25))
+    ///     .returns(());
+    /// thermostat.set_temperature(25);
+    /// ```
+    pub fn between<T>(&self, lower: T, upper: T) -> InRangeArgumentMatcher<T, RangeInclusive<T>> {
+        self.in_range(lower..=upper)
+    }
+
+    /// Creates an argument matcher that matches values contained in `range` using [`PartialOrd`].
+    ///
+    /// All kinds of ranges are supported, e.g. `3..7`, `3..=7` or `3..`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: u8);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.in_range(18..25))
+    ///     .returns(());
+    /// thermostat.set_temperature(21);
+    /// ```
+    pub fn in_range<T, R>(&self, range: R) -> InRangeArgumentMatcher<T, R>
+    where
+        R: RangeBounds<T>,
+    {
+        InRangeArgumentMatcher {
+            range,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+pub struct GreaterThanArgumentMatcher<T> {
+    value: T,
+}
+
+impl<T> Display for GreaterThanArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "> {:?}", MaybeDebugWrapper(&self.value))
+    }
+}
+
+impl<T> Debug for GreaterThanArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type GreaterThanArgumentMatcher<T>))
+            .field(name_of!(value in Self), &MaybeDebugWrapper(&self.value))
+            .finish()
+    }
+}
+
+impl<T, U> ArgumentMatcher<U> for GreaterThanArgumentMatcher<T>
+where
+    T: PartialOrd<U> + MaybeDebug,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.value < *input
+    }
+}
+
+pub struct LessThanArgumentMatcher<T> {
+    value: T,
+}
+
+impl<T> Display for LessThanArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "< {:?}", MaybeDebugWrapper(&self.value))
+    }
+}
+
+impl<T> Debug for LessThanArgumentMatcher<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type LessThanArgumentMatcher<T>))
+            .field(name_of!(value in Self), &MaybeDebugWrapper(&self.value))
+            .finish()
+    }
+}
+
+impl<T, U> ArgumentMatcher<U> for LessThanArgumentMatcher<T>
+where
+    T: PartialOrd<U> + MaybeDebug,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.value > *input
+    }
+}
+
+pub struct InRangeArgumentMatcher<T, R> {
+    range: R,
+    phantom_data: PhantomData<fn() -> T>,
+}
+
+impl<T, R> Display for InRangeArgumentMatcher<T, R>
+where
+    T: MaybeDebug,
+    R: RangeBounds<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.range.start_bound() {
+            Bound::Included(start) => write!(f, "{:?}", MaybeDebugWrapper(start))?,
+            Bound::Excluded(start) => write!(f, "{:?}<", MaybeDebugWrapper(start))?,
+            Bound::Unbounded => {}
+        }
+
+        match self.range.end_bound() {
+            Bound::Included(end) => write!(f, "..={:?}", MaybeDebugWrapper(end)),
+            Bound::Excluded(end) => write!(f, "..{:?}", MaybeDebugWrapper(end)),
+            Bound::Unbounded => write!(f, ".."),
+        }
+    }
+}
+
+impl<T, R> Debug for InRangeArgumentMatcher<T, R>
+where
+    T: MaybeDebug,
+    R: RangeBounds<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type InRangeArgumentMatcher<T, R>))
+            .field(name_of!(range in Self), &format_args!("{}", self))
+            .finish()
+    }
+}
+
+impl<T, R, U> ArgumentMatcher<U> for InRangeArgumentMatcher<T, R>
+where
+    T: PartialOrd<U> + MaybeDebug,
+    R: RangeBounds<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        let is_after_start = match self.range.start_bound() {
+            Bound::Included(start) => *start <= *input,
+            Bound::Excluded(start) => *start < *input,
+            Bound::Unbounded => true,
+        };
+
+        let is_before_end = match self.range.end_bound() {
+            Bound::Included(end) => *end >= *input,
+            Bound::Excluded(end) => *end > *input,
+            Bound::Unbounded => true,
+        };
+
+        is_after_start && is_before_end
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn greater_than_matches_greater_values() {
+        let factory = Argument::internal_new();
+        let matcher = factory.greater_than(5);
+
+        assert!(matcher.matches_argument(&6));
+        assert!(!matcher.matches_argument(&5));
+    }
+
+    #[test]
+    fn less_than_matches_smaller_values() {
+        let factory = Argument::internal_new();
+        let matcher = factory.less_than(5);
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&5));
+    }
+
+    #[test]
+    fn between_includes_bounds() {
+        let factory = Argument::internal_new();
+        let matcher = factory.between(3, 7);
+
+        assert!(matcher.matches_argument(&3));
+        assert!(matcher.matches_argument(&7));
+        assert!(!matcher.matches_argument(&2));
+        assert!(!matcher.matches_argument(&8));
+    }
+
+    #[test]
+    fn in_range_excludes_end_of_exclusive_range() {
+        let factory = Argument::internal_new();
+        let matcher = factory.in_range(3..7);
+
+        assert!(matcher.matches_argument(&6));
+        assert!(!matcher.matches_argument(&7));
+    }
+
+    #[test]
+    fn in_range_supports_unbounded_ranges() {
+        let factory = Argument::internal_new();
+
+        assert!(factory.in_range(3..).matches_argument(&1000));
+        assert!(!factory.in_range(..3).matches_argument(&3));
+    }
+
+    #[cfg(rustc_is_nightly)]
+    #[test]
+    fn matchers_have_display_output() {
+        let factory = Argument::internal_new();
+
+        assert_eq!("> 5", factory.greater_than(5).to_string());
+        assert_eq!("< 5", factory.less_than(5).to_string());
+        assert_eq!("3..=7", factory.between(3, 7).to_string());
+        assert_eq!("3..7", factory.in_range(3..7).to_string());
+        assert_eq!("3..", factory.in_range(3..).to_string());
+        assert_eq!("..=7", factory.in_range(..=7).to_string());
+    }
+}
@@ -115,7 +115,7 @@ where
     }
 }
 
-impl<'args, T, U> ArgumentMatcher<&'args U> for OwnedPartialEqArgumentMatcher<T>
+impl<T, U> ArgumentMatcher<&U> for OwnedPartialEqArgumentMatcher<T>
 where
     T: PartialEq<U> + MaybeDebug,
 {
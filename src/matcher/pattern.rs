@@ -0,0 +1,146 @@
+use super::ArgumentMatcher;
+use nameof::name_of;
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+
+#[cfg_attr(rustc_is_nightly, allow(clippy::needless_doctest_main))]
+/// Creates an argument matcher that matches values using a pattern, like [`matches!`].
+/// An optional guard can be added using `if`.
+///
+/// Failed calls show the source text of the pattern.
+/// The argument can be either the type matched by the pattern or a reference to it.
+///
+/// # Examples
+/// ```
+/// use mockiato::{matches_pattern, mockable};
+///
+/// #[derive(Debug)]
+/// enum Message {
+///     Ping { id: u32 },
+///     Text(String),
+/// }
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait MessageSender {
+///     fn send_message(&self, message: &Message);
+/// }
+///
+/// # fn main() {
+/// let mut sender = MessageSenderMock::new();
+/// sender
+///     .expect_send_message(|_| matches_pattern!(Message::Ping { .. }))
+///     .returns(());
+/// sender
+///     .expect_send_message(|_| matches_pattern!(Message::Text(text) if text.starts_with("Hi")))
+///     .returns(());
+/// sender.send_message(&Message::Ping { id: 42 });
+/// sender.send_message(&Message::Text(String::from("Hi there")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! matches_pattern {
+    ($($pattern:pat)|+ $(if $guard:expr)?) => {
+        $crate::internal::PatternArgumentMatcher::new(
+            concat!(stringify!($($pattern)|+) $(, " if ", stringify!($guard))?),
+            |value| match value {
+                $($pattern)|+ $(if $guard)? => true,
+                _ => false,
+            },
+        )
+    };
+}
+
+/// Matches values using a pattern.
+/// Created using [`matches_pattern!`](crate::matches_pattern).
+pub struct PatternArgumentMatcher<T, F> {
+    pattern: &'static str,
+    predicate: F,
+    phantom_data: PhantomData<fn(T)>,
+}
+
+impl<T, F> PatternArgumentMatcher<T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    #[doc(hidden)]
+    pub fn new(pattern: &'static str, predicate: F) -> Self {
+        Self {
+            pattern,
+            predicate,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Display for PatternArgumentMatcher<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.pattern)
+    }
+}
+
+impl<T, F> Debug for PatternArgumentMatcher<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type PatternArgumentMatcher<T, F>))
+            .field(name_of!(pattern in Self), &self.pattern)
+            .finish()
+    }
+}
+
+impl<T, F, U> ArgumentMatcher<U> for PatternArgumentMatcher<T, F>
+where
+    F: Fn(&T) -> bool,
+    U: Borrow<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        (self.predicate)(input.borrow())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    enum Message {
+        Ping,
+        Text(String),
+    }
+
+    #[test]
+    fn matches_values_matching_pattern() {
+        let matcher = matches_pattern!(Message::Ping);
+
+        assert!(matcher.matches_argument(&Message::Ping));
+        assert!(!matcher.matches_argument(&Message::Text(String::new())));
+    }
+
+    #[test]
+    fn matches_references_to_values_matching_pattern() {
+        let matcher = matches_pattern!(Some(Message::Ping) | None);
+
+        assert!(matcher.matches_argument(&&None));
+        assert!(matcher.matches_argument(&&Some(Message::Ping)));
+        assert!(!matcher.matches_argument(&&Some(Message::Text(String::new()))));
+    }
+
+    #[test]
+    fn guard_is_applied() {
+        let matcher = matches_pattern!(Message::Text(text) if text.is_empty());
+
+        assert!(matcher.matches_argument(&Message::Text(String::new())));
+        assert!(!matcher.matches_argument(&Message::Text(String::from("foo"))));
+    }
+
+    #[test]
+    fn display_shows_pattern_source() {
+        assert_eq!("Message::Ping", matches_pattern!(Message::Ping).to_string());
+        assert_eq!(
+            "Message::Text(text) if text.is_empty()",
+            matches_pattern!(Message::Text(text) if text.is_empty()).to_string()
+        );
+    }
+}
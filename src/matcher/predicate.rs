@@ -0,0 +1,115 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches values for which `predicate` returns `true`.
+    ///
+    /// The type of the predicate's parameter needs to be annotated, e.g. `|value: &&str|`
+    /// for an argument of type `&str`.
+    /// Failed calls show the matcher as `<predicate>`, unless a description
+    /// is provided using `described_as`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, message: &str);
+    /// }
+    ///
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| {
+    ///         arg.matches(|message: &&str| message.starts_with("Hello"))
+    ///             .described_as("a greeting")
+    ///     })
+    ///     .returns(());
+    /// sender.send_message("Hello World");
+    /// ```
+    pub fn matches<F>(&self, predicate: F) -> PredicateArgumentMatcher<F> {
+        PredicateArgumentMatcher {
+            predicate,
+            description: None,
+        }
+    }
+}
+
+/// Matches values for which a predicate returns `true`.
+/// Created using [`Argument::matches`].
+pub struct PredicateArgumentMatcher<F> {
+    predicate: F,
+    description: Option<String>,
+}
+
+impl<F> PredicateArgumentMatcher<F> {
+    /// Describes the values matched by the predicate.
+    /// The description is shown in the messages of failed calls.
+    pub fn described_as(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl<F> Display for PredicateArgumentMatcher<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.description {
+            Some(ref description) => write!(f, "{}", description),
+            None => write!(f, "<predicate>"),
+        }
+    }
+}
+
+impl<F> Debug for PredicateArgumentMatcher<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type PredicateArgumentMatcher<F>))
+            .field(name_of!(description in Self), &self.description)
+            .finish()
+    }
+}
+
+impl<T, F> ArgumentMatcher<T> for PredicateArgumentMatcher<F>
+where
+    F: Fn(&T) -> bool,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        (self.predicate)(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_values_accepted_by_predicate() {
+        let factory = Argument::internal_new();
+        let matcher = factory.matches(|value: &u32| *value > 3);
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&3));
+    }
+
+    #[test]
+    fn display_uses_placeholder_without_description() {
+        let factory = Argument::internal_new();
+        let matcher = factory.matches(|_: &u32| true);
+
+        assert_eq!("<predicate>", matcher.to_string());
+    }
+
+    #[test]
+    fn display_uses_description() {
+        let factory = Argument::internal_new();
+        let matcher = factory
+            .matches(|value: &u32| *value > 3)
+            .described_as("greater than 3");
+
+        assert_eq!("greater than 3", matcher.to_string());
+    }
+}
@@ -0,0 +1,296 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches strings containing `pattern`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Database {
+    ///     fn execute(&self, query: &str);
+    /// }
+    ///
+    /// let mut database = DatabaseMock::new();
+    /// database
+    ///     .expect_execute(|arg| arg.contains("FROM users"))
+    ///     .returns(());
+    /// database.execute("SELECT name FROM users");
+    /// ```
+    pub fn contains(&self, pattern: impl Into<String>) -> StringArgumentMatcher {
+        StringArgumentMatcher::new(StringMatcherKind::Contains, pattern)
+    }
+
+    /// Creates an argument matcher that matches strings starting with `prefix`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Logger {
+    ///     fn log(&self, line: String);
+    /// }
+    ///
+    /// let mut logger = LoggerMock::new();
+    /// logger.expect_log(|arg| arg.starts_with("ERROR")).returns(());
+    /// logger.log(String::from("ERROR: disk full"));
+    /// ```
+    pub fn starts_with(&self, prefix: impl Into<String>) -> StringArgumentMatcher {
+        StringArgumentMatcher::new(StringMatcherKind::StartsWith, prefix)
+    }
+
+    /// Creates an argument matcher that matches strings ending with `suffix`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait FileSystem {
+    ///     fn remove(&self, path: &str);
+    /// }
+    ///
+    /// let mut file_system = FileSystemMock::new();
+    /// file_system
+    ///     .expect_remove(|arg| arg.ends_with(".tmp"))
+    ///     .returns(());
+    /// file_system.remove("/var/cache/download.tmp");
+    /// ```
+    pub fn ends_with(&self, suffix: impl Into<String>) -> StringArgumentMatcher {
+        StringArgumentMatcher::new(StringMatcherKind::EndsWith, suffix)
+    }
+
+    /// Creates an argument matcher that matches strings that are equal to `value`
+    /// when ignoring the case of ASCII characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Database {
+    ///     fn execute(&self, query: &str);
+    /// }
+    ///
+    /// let mut database = DatabaseMock::new();
+    /// database
+    ///     .expect_execute(|arg| arg.eq_ignore_ascii_case("DELETE FROM users"))
+    ///     .returns(());
+    /// database.execute("delete from users");
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, value: impl Into<String>) -> StringArgumentMatcher {
+        StringArgumentMatcher::new(StringMatcherKind::EqIgnoreAsciiCase, value)
+    }
+
+    /// Creates an argument matcher that matches strings against a glob `pattern`.
+    ///
+    /// `*` matches any sequence of characters (including none) and `?` matches
+    /// exactly one character. All other characters are matched literally.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Cache {
+    ///     fn invalidate(&self, key: &str);
+    /// }
+    ///
+    /// let mut cache = CacheMock::new();
+    /// cache
+    ///     .expect_invalidate(|arg| arg.glob("user-*-id"))
+    ///     .returns(());
+    /// cache.invalidate("user-42-id");
+    /// ```
+    pub fn glob(&self, pattern: impl Into<String>) -> StringArgumentMatcher {
+        StringArgumentMatcher::new(StringMatcherKind::Glob, pattern)
+    }
+}
+
+/// Matches strings against a pattern.
+/// Matches all types that can be referenced as [`str`], e.g. `&str`, [`String`] and `&String`.
+pub struct StringArgumentMatcher {
+    kind: StringMatcherKind,
+    pattern: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringMatcherKind {
+    Contains,
+    StartsWith,
+    EndsWith,
+    EqIgnoreAsciiCase,
+    Glob,
+}
+
+impl StringArgumentMatcher {
+    fn new(kind: StringMatcherKind, pattern: impl Into<String>) -> Self {
+        Self {
+            kind,
+            pattern: pattern.into(),
+        }
+    }
+
+    fn matches_str(&self, input: &str) -> bool {
+        let pattern = self.pattern.as_str();
+
+        match self.kind {
+            StringMatcherKind::Contains => input.contains(pattern),
+            StringMatcherKind::StartsWith => input.starts_with(pattern),
+            StringMatcherKind::EndsWith => input.ends_with(pattern),
+            StringMatcherKind::EqIgnoreAsciiCase => input.eq_ignore_ascii_case(pattern),
+            StringMatcherKind::Glob => glob_matches(pattern, input),
+        }
+    }
+}
+
+impl Display for StringArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.kind {
+            StringMatcherKind::Contains => "contains",
+            StringMatcherKind::StartsWith => "starts_with",
+            StringMatcherKind::EndsWith => "ends_with",
+            StringMatcherKind::EqIgnoreAsciiCase => "eq_ignore_ascii_case",
+            StringMatcherKind::Glob => "glob",
+        };
+
+        write!(f, "{}({:?})", name, self.pattern)
+    }
+}
+
+impl Debug for StringArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type StringArgumentMatcher))
+            .field(name_of!(kind in Self), &self.kind)
+            .field(name_of!(pattern in Self), &self.pattern)
+            .finish()
+    }
+}
+
+impl<U> ArgumentMatcher<U> for StringArgumentMatcher
+where
+    U: AsRef<str>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.matches_str(input.as_ref())
+    }
+}
+
+/// Matches `input` against a glob `pattern` containing `*` and `?` wildcards.
+fn glob_matches(pattern: &str, input: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let input: Vec<char> = input.chars().collect();
+
+    let mut pattern_index = 0;
+    let mut input_index = 0;
+    // The position of the last `*` in the pattern and the position in the input
+    // it was matched against, used for backtracking.
+    let mut last_wildcard: Option<(usize, usize)> = None;
+
+    while input_index < input.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                last_wildcard = Some((pattern_index, input_index));
+                pattern_index += 1;
+            }
+            Some(&character) if character == '?' || character == input[input_index] => {
+                pattern_index += 1;
+                input_index += 1;
+            }
+            _ => match last_wildcard {
+                Some((wildcard_index, matched_index)) => {
+                    last_wildcard = Some((wildcard_index, matched_index + 1));
+                    pattern_index = wildcard_index + 1;
+                    input_index = matched_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[pattern_index..]
+        .iter()
+        .all(|&character| character == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_str_string_and_string_reference() {
+        let factory = Argument::internal_new();
+        let matcher = factory.contains("oo");
+        let owned = String::from("foo");
+
+        assert!(matcher.matches_argument(&"foo"));
+        assert!(matcher.matches_argument(&owned));
+        assert!(matcher.matches_argument(&&owned));
+        assert!(!matcher.matches_argument(&"bar"));
+    }
+
+    #[test]
+    fn prefix_and_suffix_are_matched() {
+        let factory = Argument::internal_new();
+
+        assert!(factory.starts_with("foo").matches_argument(&"foobar"));
+        assert!(!factory.starts_with("bar").matches_argument(&"foobar"));
+        assert!(factory.ends_with("bar").matches_argument(&"foobar"));
+        assert!(!factory.ends_with("foo").matches_argument(&"foobar"));
+    }
+
+    #[test]
+    fn ascii_case_is_ignored() {
+        let factory = Argument::internal_new();
+        let matcher = factory.eq_ignore_ascii_case("Foo");
+
+        assert!(matcher.matches_argument(&"fOO"));
+        assert!(!matcher.matches_argument(&"fo"));
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("user-*-id", "user-42-id"));
+        assert!(glob_matches("user-*-id", "user--id"));
+        assert!(glob_matches("user-*-id", "user-a-id-b-id"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("a?c", "abc"));
+        assert!(glob_matches("*.tmp", "download.tmp"));
+        assert!(!glob_matches("a?c", "ac"));
+        assert!(!glob_matches("user-*-id", "user-42-idx"));
+        assert!(!glob_matches("user-*-id", "admin-42-id"));
+        assert!(!glob_matches("", "a"));
+    }
+
+    #[test]
+    fn display_shows_pattern() {
+        let factory = Argument::internal_new();
+
+        assert_eq!(r#"contains("foo")"#, factory.contains("foo").to_string());
+        assert_eq!(r#"glob("user-*")"#, factory.glob("user-*").to_string());
+        assert_eq!(
+            r#"eq_ignore_ascii_case("Foo")"#,
+            factory.eq_ignore_ascii_case("Foo").to_string()
+        );
+    }
+}
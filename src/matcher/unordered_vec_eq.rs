@@ -1,3 +1,4 @@
+use super::collection::ArgumentCollection;
 use super::ArgumentMatcher;
 use crate::argument::Argument;
 use crate::fmt::MaybeDebugWrapper;
@@ -5,9 +6,12 @@ use nameof::name_of;
 use std::fmt::{self, Debug, Display};
 
 impl Argument {
-    /// Creates an argument matcher that matches against [`Vec`]s and [`slice`]s
+    /// Creates an argument matcher that matches against [`Vec`]s, [`slice`]s and other collections
     /// while disregarding the exact order of the elements.
     ///
+    /// Works with slices, [`Vec`], [`VecDeque`], [`HashSet`], [`BTreeSet`]
+    /// and the keys of a [`HashMap`].
+    ///
     /// Requires the elements to implement [`PartialEq`].
     ///
     /// # Examples
@@ -31,6 +35,10 @@ impl Argument {
     /// ```
     ///
     /// [`slice`]: https://doc.rust-lang.org/std/primitive.slice.html
+    /// [`VecDeque`]: std::collections::VecDeque
+    /// [`HashSet`]: std::collections::HashSet
+    /// [`BTreeSet`]: std::collections::BTreeSet
+    /// [`HashMap`]: std::collections::HashMap
     pub fn unordered_vec_eq<T>(&self, vec: Vec<T>) -> UnorderedVecArgumentMatcher<T> {
         UnorderedVecArgumentMatcher(vec)
     }
@@ -52,33 +60,15 @@ impl<T> Debug for UnorderedVecArgumentMatcher<T> {
     }
 }
 
-impl<T, U> ArgumentMatcher<Vec<U>> for UnorderedVecArgumentMatcher<T>
-where
-    T: PartialEq<U>,
-    U: PartialEq<T>,
-{
-    fn matches_argument(&self, input: &Vec<U>) -> bool {
-        compare_slices_unordered(&self.0, input.as_slice())
-    }
-}
-
-impl<'a, T, U> ArgumentMatcher<&'a [U]> for UnorderedVecArgumentMatcher<T>
-where
-    T: PartialEq<U>,
-    U: PartialEq<T>,
-{
-    fn matches_argument(&self, input: &&'a [U]) -> bool {
-        compare_slices_unordered(&self.0, *input)
-    }
-}
-
-impl<'a, T, U> ArgumentMatcher<&'a mut [U]> for UnorderedVecArgumentMatcher<T>
+impl<T, C> ArgumentMatcher<C> for UnorderedVecArgumentMatcher<T>
 where
-    T: PartialEq<U>,
-    U: PartialEq<T>,
+    T: PartialEq<C::Element>,
+    C: ArgumentCollection,
+    C::Element: PartialEq<T>,
 {
-    fn matches_argument(&self, input: &&'a mut [U]) -> bool {
-        compare_slices_unordered(&self.0, *input)
+    fn matches_argument(&self, input: &C) -> bool {
+        let expected: Vec<_> = self.0.iter().collect();
+        compare_slices_unordered(&expected, &input.elements())
     }
 }
 
@@ -105,6 +95,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn accepts_empty_slices() {
@@ -140,6 +131,17 @@ mod test {
         ))
     }
 
+    #[test]
+    fn matches_sets() {
+        let factory = Argument::internal_new();
+        let set: HashSet<_> = vec!["foo", "bar"].into_iter().collect();
+
+        assert!(factory
+            .unordered_vec_eq(vec!["bar", "foo"])
+            .matches_argument(&set));
+        assert!(!factory.unordered_vec_eq(vec!["bar"]).matches_argument(&set));
+    }
+
     #[test]
     fn rejects_when_actual_slice_has_extra_elements() {
         assert!(!compare_slices_unordered(&["foo"], &["foo", "bar"]))
@@ -1,12 +1,19 @@
-use crate::matcher::ArgumentsMatcher;
+use crate::call_history::{CallHistory, RecordedCall};
+use crate::error::{CallError, CallErrorKind, VerificationError};
+use crate::fallback::{DefaultFallbackFactory, FallbackBuilder};
+use crate::matcher::{ArgumentsMatcher, RecordArguments};
 use crate::method_call::{MethodCall, MethodCallBuilder};
+use crate::return_value::{self, FromReturnValueGenerator, ReturnValueGenerator};
+use crate::storage::{Cell, Shared, StorageCell, UsesStorage};
 use nameof::name_of;
+use std::borrow::Cow;
 use std::fmt::{self, Debug, Display};
 
 #[cfg(rustc_is_nightly)]
-const FOOTER: &str = "";
+pub(crate) const FOOTER: &str = "";
 #[cfg(not(rustc_is_nightly))]
-const FOOTER: &str = "\n\n----\nSome mockiato messages may not be displayed correctly. Re-run \
+pub(crate) const FOOTER: &str =
+    "\n\n----\nSome mockiato messages may not be displayed correctly. Re-run \
                       with nightly rust to see full error messages.\n----\n\n";
 
 #[derive(Clone, Debug)]
@@ -16,69 +23,170 @@ enum ExpectedCallOrder {
 }
 
 #[allow(missing_docs)]
-pub struct Method<'mock, A, R>
+pub struct Method<'mock, A, R, G = dyn ReturnValueGenerator<A, R> + 'mock>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
-    name: &'static str,
-    calls: Vec<MethodCall<'mock, A, R>>,
+    name: Cow<'static, str>,
+    calls: Vec<MethodCall<'mock, A, R, G>>,
     call_order: ExpectedCallOrder,
+    call_errors: Cell<G, Vec<CallError>>,
+    allows_unexpected_calls: bool,
+    fallback: Option<Shared<G, G>>,
+    unexpected_calls: Cell<G, Vec<CallError>>,
+    real_implementation: Option<Shared<G, G>>,
+    recorded_calls: Cell<G, Vec<RecordedCall<<A as RecordArguments>::RecordedArguments>>>,
 }
 
-impl<'mock, A, R> Debug for Method<'mock, A, R>
+impl<'mock, A, R, G> Debug for Method<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type Method<'mock, A, R>))
+        f.debug_struct(name_of!(type Method<'mock, A, R, G>))
             .field(name_of!(name in Self), &self.name)
             .field(name_of!(calls in Self), &self.calls)
             .field(name_of!(call_order in Self), &self.call_order)
+            .field(name_of!(call_errors in Self), &self.call_errors())
+            .field(
+                name_of!(allows_unexpected_calls in Self),
+                &self.allows_unexpected_calls,
+            )
+            .field(name_of!(fallback in Self), &self.fallback.as_deref())
+            .field(name_of!(unexpected_calls in Self), &self.unexpected_calls())
+            .field(
+                name_of!(real_implementation in Self),
+                &self.real_implementation.as_deref(),
+            )
+            .field(
+                name_of!(recorded_calls in Self),
+                &self
+                    .recorded_calls()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
 
-impl<'mock, A, R> Clone for Method<'mock, A, R>
+impl<'mock, A, R, G> Clone for Method<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn clone(&self) -> Self {
         Self {
-            name: self.name,
+            name: self.name.clone(),
             calls: self.calls.clone(),
             call_order: self.call_order.clone(),
+            call_errors: StorageCell::new(self.call_errors()),
+            allows_unexpected_calls: self.allows_unexpected_calls,
+            fallback: self.fallback.clone(),
+            unexpected_calls: StorageCell::new(self.unexpected_calls()),
+            real_implementation: self.real_implementation.clone(),
+            recorded_calls: StorageCell::new(self.recorded_calls()),
         }
     }
 }
 
 #[allow(missing_docs)]
-impl<'mock, A, R> Method<'mock, A, R>
+impl<'mock, A, R, G> Method<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
-    pub fn new(name: &'static str) -> Self {
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
         Self {
-            name,
+            name: name.into(),
             calls: Vec::new(),
             call_order: ExpectedCallOrder::Unordered,
+            call_errors: StorageCell::new(Vec::new()),
+            allows_unexpected_calls: false,
+            fallback: None,
+            unexpected_calls: StorageCell::new(Vec::new()),
+            real_implementation: None,
+            recorded_calls: StorageCell::new(Vec::new()),
         }
     }
 
-    pub fn add_expected_call(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R> {
+    pub fn add_expected_call(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R, G> {
         let call = MethodCall::new(matcher);
 
         self.calls.push(call);
 
-        MethodCallBuilder::new(self.calls.last_mut().unwrap())
+        MethodCallBuilder::new(
+            &self.name,
+            self.calls.last_mut().unwrap(),
+            self.real_implementation.clone(),
+        )
     }
 
     pub fn expect_method_calls_in_order(&mut self) {
         self.call_order = ExpectedCallOrder::Sequentially;
     }
 
+    /// Returns the calls that were answered by this method,
+    /// including calls answered by the fallback or the real implementation.
+    pub fn call_history(&self) -> CallHistory<<A as RecordArguments>::RecordedArguments> {
+        CallHistory::new(self.name.to_string(), self.recorded_calls())
+    }
+
     pub fn call_unwrap<'a>(&'a self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R {
-        self.call(arguments)
-            .unwrap_or_else(|err| panic!("\n\n{}{}\n", err, FOOTER))
+        self.call(arguments).unwrap_or_else(|err| {
+            let message = format!("\n\n{}{}\n", err, FOOTER);
+            self.call_errors.with(|call_errors| call_errors.push(err));
+            panic!("{}", message)
+        })
+    }
+
+    /// Returns the calls to this method that were not expected.
+    pub fn call_errors(&self) -> Vec<CallError> {
+        self.call_errors.with(|call_errors| call_errors.clone())
+    }
+
+    /// Calls that don't match any expected call return the value of the fallback
+    /// instead of panicking. `default_fallback` is used if no fallback was configured.
+    pub fn allow_unexpected_calls(&mut self, default_fallback: Option<Shared<G, G>>) {
+        self.allows_unexpected_calls = true;
+
+        if self.fallback.is_none() {
+            self.fallback = default_fallback;
+        }
+    }
+
+    /// Creates a factory for the fallback that is used if no fallback was configured.
+    /// See [`DefaultFallbackFactory`] on how this is used.
+    pub fn default_fallback_factory(&self) -> DefaultFallbackFactory<A, R, G> {
+        DefaultFallbackFactory::new()
+    }
+
+    /// Configures the return value of calls that don't match any expected call.
+    pub fn fallback(&mut self) -> FallbackBuilder<'mock, '_, A, R, G> {
+        FallbackBuilder::new(&mut self.fallback)
+    }
+
+    /// Forwards calls that don't match any expected call, and expected calls configured
+    /// using `calls_through`, to the given closure, which calls the real implementation.
+    pub fn set_real_implementation<F>(&mut self, closure: F)
+    where
+        F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R + 'mock,
+        G: FromReturnValueGenerator<return_value::CallsThrough<F>>,
+    {
+        self.real_implementation = Some(G::from_return_value_generator(
+            return_value::CallsThrough(closure),
+        ));
+    }
+
+    /// Returns the calls that didn't match any expected call and were answered by the fallback.
+    pub fn unexpected_calls(&self) -> Vec<CallError> {
+        self.unexpected_calls
+            .with(|unexpected_calls| unexpected_calls.clone())
     }
 
     pub fn verify_unwrap(&self) {
@@ -86,38 +194,86 @@ where
             .unwrap_or_else(|err| panic!("{}{}", err, FOOTER))
     }
 
+    pub fn verification_errors(&self) -> Vec<VerificationError> {
+        self.verify().err().into_iter().collect()
+    }
+
+    /// Removes all expected calls, recorded calls, call errors and unexpected calls.
+    pub fn clear(&mut self) {
+        self.calls.clear();
+        self.recorded_calls
+            .with(|recorded_calls| recorded_calls.clear());
+        self.call_errors.with(|call_errors| call_errors.clear());
+        self.unexpected_calls
+            .with(|unexpected_calls| unexpected_calls.clear());
+    }
+
+    fn verify(&self) -> Result<(), VerificationError> {
+        if self
+            .calls
+            .iter()
+            .any(|method_call| !method_call.was_called_expected_number_of_times())
+        {
+            Err(VerificationError::new(
+                self.name.to_string(),
+                self.calls.iter().map(MethodCall::report).collect(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     fn call<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
-        match self.call_order {
+    ) -> Result<R, CallError> {
+        // The arguments are recorded before they are moved into the return value generator,
+        // but the call is only added to the history once it was answered.
+        let recorded_call = RecordedCall::new(
+            <A as ArgumentsMatcher<'a>>::record_arguments(&arguments),
+            arguments.to_string(),
+        );
+
+        let result = match self.call_order {
             ExpectedCallOrder::Sequentially => {
                 self.handle_call_with_sequentially_ordered_expectations(arguments)
             }
             ExpectedCallOrder::Unordered => self.handle_call_with_unordered_expectations(arguments),
+        };
+
+        if result.is_ok() {
+            self.recorded_calls
+                .with(|recorded_calls| recorded_calls.push(recorded_call));
         }
+
+        result
+    }
+
+    fn recorded_calls(&self) -> Vec<RecordedCall<<A as RecordArguments>::RecordedArguments>> {
+        self.recorded_calls
+            .with(|recorded_calls| recorded_calls.clone())
     }
 
     fn handle_call_with_sequentially_ordered_expectations<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
+    ) -> Result<R, CallError> {
         let matching_method_call = self.calls.iter().find(|call| call.accepts_more_calls());
 
         match matching_method_call {
             Some(matching_method_call)
                 if matching_method_call.matches_expected_arguments(&arguments) =>
             {
-                Ok(matching_method_call.call(arguments))
+                self.call_expected_call(matching_method_call, arguments)
             }
-            _ => Err(CallError::NoMatching(arguments, self)),
+            _ => self.handle_unexpected_call(arguments),
         }
     }
 
     fn handle_call_with_unordered_expectations<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
+    ) -> Result<R, CallError> {
         let matching_method_calls = self
             .calls
             .iter()
@@ -125,121 +281,67 @@ where
             .collect::<Vec<_>>();
 
         match matching_method_calls.len() {
-            0 => Err(CallError::NoMatching(arguments, self)),
+            0 => self.handle_unexpected_call(arguments),
             1 => {
                 let expected_call = matching_method_calls.first().unwrap();
                 if expected_call.accepts_more_calls() {
-                    Ok(expected_call.call(arguments))
+                    self.call_expected_call(expected_call, arguments)
                 } else {
-                    Err(CallError::NoMatching(arguments, self))
+                    self.handle_unexpected_call(arguments)
                 }
             }
-            _ => Err(CallError::MoreThanOneMatching(
-                arguments,
-                self,
-                matching_method_calls,
+            _ => Err(self.call_error(
+                &arguments,
+                CallErrorKind::MoreThanOneMatchingCall {
+                    matching_calls: matching_method_calls
+                        .iter()
+                        .map(|call| call.report())
+                        .collect(),
+                },
             )),
         }
     }
 
-    fn verify(&self) -> Result<(), VerificationError<'mock, '_, A, R>> {
-        if self
-            .calls
-            .iter()
-            .any(|method_call| !method_call.was_called_expected_number_of_times())
-        {
-            Err(VerificationError { method: self })
-        } else {
-            Ok(())
-        }
-    }
-}
-
-#[derive(Debug)]
-enum CallError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    NoMatching(
-        <A as ArgumentsMatcher<'a>>::Arguments,
-        &'a Method<'mock, A, R>,
-    ),
-    MoreThanOneMatching(
-        <A as ArgumentsMatcher<'a>>::Arguments,
-        &'a Method<'mock, A, R>,
-        Vec<&'a MethodCall<'mock, A, R>>,
-    ),
-}
-
-impl<'mock, 'a, A, R> Display for CallError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            CallError::NoMatching(arguments, method) => {
-                writeln!(f, "The call {}{} was not expected.", method.name, arguments)?;
-
-                if method.calls.is_empty() {
-                    writeln!(f, "No calls to {} were expected.", method.name)
-                } else {
-                    writeln!(
-                        f,
-                        "The following calls were expected:\n{}",
-                        DisplayCalls(&method.calls.iter().collect::<Vec<_>>())
-                    )
-                }
+    fn call_expected_call<'a>(
+        &'a self,
+        expected_call: &'a MethodCall<'mock, A, R, G>,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<R, CallError> {
+        match expected_call.enter_sequence() {
+            Ok(()) => Ok(expected_call.call(arguments)),
+            Err(sequence_error) => {
+                Err(self.call_error(&arguments, CallErrorKind::OutOfSequence(sequence_error)))
             }
-            CallError::MoreThanOneMatching(arguments, method, calls) => writeln!(
-                f,
-                "\nThe call {}{} matches more than one expected call:\n{}",
-                method.name,
-                arguments,
-                DisplayCalls(calls)
-            ),
         }
     }
-}
-
-#[derive(Debug)]
-struct VerificationError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    method: &'a Method<'mock, A, R>,
-}
 
-impl<'mock, 'a, A, R> Display for VerificationError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "The expected calls for {} were not satisified.",
-            self.method.name
-        )?;
-
-        for call in &self.method.calls {
-            writeln!(f, "{}", call)?;
+    fn handle_unexpected_call<'a>(
+        &'a self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<R, CallError> {
+        if let Some(ref real_implementation) = self.real_implementation {
+            return Ok(real_implementation.generate_return_value(arguments));
         }
 
-        Ok(())
-    }
-}
-
-struct DisplayCalls<'mock, 'a, A, R>(&'a [&'a MethodCall<'mock, A, R>]);
-
-impl<'mock, 'a, A, R> Display for DisplayCalls<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for call in self.0 {
-            writeln!(f, "{}", call)?;
+        let error = self.call_error(
+            &arguments,
+            CallErrorKind::NoMatchingCall {
+                expected_calls: self.calls.iter().map(MethodCall::report).collect(),
+            },
+        );
+
+        match self.fallback {
+            Some(ref fallback) if self.allows_unexpected_calls => {
+                self.unexpected_calls
+                    .with(|unexpected_calls| unexpected_calls.push(error));
+                Ok(fallback.generate_return_value(arguments))
+            }
+            _ => Err(error),
         }
+    }
 
-        Ok(())
+    fn call_error(&self, arguments: &dyn Display, kind: CallErrorKind) -> CallError {
+        CallError::new(self.name.to_string(), arguments.to_string(), kind)
     }
 }
 
@@ -258,9 +360,12 @@ mod test {
         method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
 
         match method.call(ArgumentsMock) {
-            Err(CallError::MoreThanOneMatching(_, _, method_calls)) => {
-                assert_eq!(2, method_calls.len());
-            }
+            Err(error) => match error.kind() {
+                CallErrorKind::MoreThanOneMatchingCall { matching_calls } => {
+                    assert_eq!(2, matching_calls.len());
+                }
+                _ => panic!("unexpected call error"),
+            },
             _ => panic!("unexpected result from method call"),
         }
     }
@@ -274,7 +379,10 @@ mod test {
             .returns(());
 
         match method.call(ArgumentsMock) {
-            Err(CallError::NoMatching(..)) => {}
+            Err(error) => match error.kind() {
+                CallErrorKind::NoMatchingCall { .. } => {}
+                _ => panic!("unexpected call error"),
+            },
             _ => panic!("unexpected result from method call"),
         }
     }
@@ -290,6 +398,72 @@ mod test {
         assert_eq!(String::from("bar"), method.call(ArgumentsMock).unwrap());
     }
 
+    #[test]
+    fn call_forwards_unmatched_calls_to_real_implementation() {
+        let mut method = Method::<_, String>::new("test");
+        method.set_real_implementation(|_| String::from("real"));
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .returns(String::from("mocked"));
+
+        assert_eq!(String::from("real"), method.call(ArgumentsMock).unwrap());
+        assert!(method.call_errors().is_empty());
+    }
+
+    #[test]
+    fn expected_call_calls_through_to_real_implementation() {
+        let mut method = Method::<_, String>::new("test");
+        method.set_real_implementation(|_| String::from("real"));
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .calls_through();
+
+        assert_eq!(String::from("real"), method.call(ArgumentsMock).unwrap());
+        assert!(method.verify().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "no real implementation is available")]
+    fn calls_through_panics_without_real_implementation() {
+        let mut method = Method::<_, String>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .calls_through();
+    }
+
+    #[test]
+    fn call_history_contains_calls_answered_by_expected_calls_fallback_and_real_implementation() {
+        let mut method = Method::<_, String>::new("test");
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(1)
+            .returns(String::from("expected"));
+
+        method.call(ArgumentsMock).unwrap();
+        method.allow_unexpected_calls(None);
+        method.fallback().returns(String::from("fallback"));
+        method.call(ArgumentsMock).unwrap();
+        method.set_real_implementation(|_| String::from("real"));
+        method.call(ArgumentsMock).unwrap();
+
+        method.call_history().assert_called_times(3);
+    }
+
+    #[test]
+    fn call_history_does_not_contain_rejected_calls() {
+        let mut method = Method::<_, ()>::new("test");
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .returns(());
+
+        assert!(method.call(ArgumentsMock).is_err());
+
+        method.call_history().assert_not_called();
+    }
+
     #[test]
     fn errors_when_matching_call_is_called_more_than_expected() {
         let mut method = Method::<_, ()>::new("test");
@@ -1,36 +1,48 @@
 use crate::default_return_value::DefaultReturnValue;
+use crate::error::ExpectedCallReport;
 use crate::expected_calls::ExpectedCalls;
 use crate::fmt::{DisplayOption, DisplayTimes};
 use crate::matcher::ArgumentsMatcher;
-use crate::return_value::{self, ReturnValueGenerator};
+use crate::return_value::{self, FromReturnValueGenerator, ReturnValueGenerator};
+use crate::sequence::{Sequence, SequenceError, SequenceStep};
+use crate::storage::{self, Cell, Shared, StorageCell, UsesStorage};
 use nameof::name_of;
-use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
-use std::rc::Rc;
+use std::marker::PhantomData;
 
 /// Configures an expected method call.
 /// This builder is returned from the `expect_*` methods on a generated mock.
-pub struct MethodCallBuilder<'mock, 'a, A, R>
+pub struct MethodCallBuilder<'mock, 'a, A, R, G = dyn ReturnValueGenerator<A, R> + 'mock>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
-    call: &'a mut MethodCall<'mock, A, R>,
+    method_name: &'a str,
+    call: &'a mut MethodCall<'mock, A, R, G>,
+    real_implementation: Option<Shared<G, G>>,
 }
 
-impl<'mock, 'a, A, R> Debug for MethodCallBuilder<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, G> Debug for MethodCallBuilder<'mock, 'a, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type MethodCallBuilder<'mock, 'a, A, R>))
+        f.debug_struct(name_of!(type MethodCallBuilder<'mock, 'a, A, R, G>))
+            .field(name_of!(method_name in Self), &self.method_name)
             .field(name_of!(call in Self), &self.call)
+            .field(
+                name_of!(real_implementation in Self),
+                &self.real_implementation.as_deref(),
+            )
             .finish()
     }
 }
 
-impl<'mock, 'a, A, R> MethodCallBuilder<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, G> MethodCallBuilder<'mock, 'a, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     /// Defines the return value for this method.
     /// The value must be [`Clone`]able.
@@ -39,8 +51,11 @@ where
     pub fn returns(&mut self, return_value: R) -> &mut Self
     where
         R: Clone + 'mock,
+        G: FromReturnValueGenerator<return_value::Cloned<R>>,
     {
-        self.call.return_value = Some(Rc::new(return_value::Cloned(return_value)));
+        self.call.return_value = Some(G::from_return_value_generator(return_value::Cloned(
+            return_value,
+        )));
         self
     }
 
@@ -51,21 +66,161 @@ where
     pub fn returns_once(&mut self, return_value: R) -> &mut Self
     where
         R: 'mock,
+        G: FromReturnValueGenerator<return_value::Once<R, <G as UsesStorage>::Storage>>,
+    {
+        self.call.return_value = Some(G::from_return_value_generator(return_value::Once::new(
+            return_value,
+        )));
+        self.assert_times_and_return_value_are_compatible();
+        self
+    }
+
+    /// Defines a closure that computes the return value for this method.
+    /// The closure receives the arguments of the call, which can be accessed by their name.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Greeter {
+    ///     fn greet(&self, name: &str) -> String;
+    /// }
+    ///
+    /// let mut greeter = GreeterMock::new();
+    ///
+    /// greeter
+    ///     .expect_greet(|arg| arg.any())
+    ///     .times(2)
+    ///     .returns_with(|args| format!("Hello {}", args.name));
+    ///
+    /// assert_eq!("Hello Jane", greeter.greet("Jane"));
+    /// assert_eq!("Hello John", greeter.greet("John"));
+    /// ```
+    pub fn returns_with<F>(&mut self, closure: F) -> &mut Self
+    where
+        F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R + 'mock,
+        G: FromReturnValueGenerator<return_value::Closure<F>>,
+    {
+        self.call.return_value = Some(G::from_return_value_generator(return_value::Closure(
+            closure,
+        )));
+        self
+    }
+
+    /// Defines a sequence of return values for this method.
+    /// Each call returns the next value of the sequence.
+    /// The values do not need to be [`Clone`]able.
+    ///
+    /// The number of expected calls must not exceed the number of values.
+    /// If the number of expected calls has no upper limit (e.g. `times(..)`),
+    /// the mocked method panics once all values were returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait IdGenerator {
+    ///     fn next_id(&self) -> u64;
+    /// }
+    ///
+    /// let mut id_generator = IdGeneratorMock::new();
+    ///
+    /// id_generator
+    ///     .expect_next_id()
+    ///     .times(3)
+    ///     .returns_in_sequence(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(1, id_generator.next_id());
+    /// assert_eq!(2, id_generator.next_id());
+    /// assert_eq!(3, id_generator.next_id());
+    /// ```
+    pub fn returns_in_sequence<I>(&mut self, return_values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = R>,
+        R: 'mock,
+        G: FromReturnValueGenerator<return_value::Successive<R, <G as UsesStorage>::Storage>>,
     {
-        self.call.return_value = Some(Rc::new(return_value::Once::new(return_value)));
+        self.call.return_value = Some(G::from_return_value_generator(
+            return_value::Successive::new(return_values),
+        ));
         self.assert_times_and_return_value_are_compatible();
         self
     }
 
     /// Defines that this method panics.
-    pub fn panics(&mut self) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(None)));
+    pub fn panics(&mut self) -> &mut Self
+    where
+        G: FromReturnValueGenerator<return_value::Panic>,
+    {
+        self.call.return_value = Some(G::from_return_value_generator(return_value::Panic(None)));
         self
     }
 
     /// Defines that this method panics with a message.
-    pub fn panics_with_message(&mut self, message: &'static str) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(Some(message))));
+    pub fn panics_with_message(&mut self, message: &'static str) -> &mut Self
+    where
+        G: FromReturnValueGenerator<return_value::Panic>,
+    {
+        self.call.return_value = Some(G::from_return_value_generator(return_value::Panic(Some(
+            message,
+        ))));
+        self
+    }
+
+    /// Defines that this method calls through to the real implementation
+    /// that was passed to the mock's `with_fallback` constructor.
+    ///
+    /// # Panics
+    /// Panics if the mock was not created using `with_fallback`
+    /// or if the method can't call through (e.g. because it is generic).
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Greeter {
+    ///     fn greet(&self, name: &str) -> String;
+    /// }
+    ///
+    /// struct FriendlyGreeter;
+    ///
+    /// impl Greeter for FriendlyGreeter {
+    ///     fn greet(&self, name: &str) -> String {
+    ///         format!("Hello {}", name)
+    ///     }
+    /// }
+    ///
+    /// let mut greeter = GreeterMock::with_fallback(FriendlyGreeter);
+    ///
+    /// greeter
+    ///     .expect_greet(|arg| arg.partial_eq("Jane"))
+    ///     .calls_through();
+    ///
+    /// assert_eq!("Hello Jane", greeter.greet("Jane"));
+    /// ```
+    pub fn calls_through(&mut self) -> &mut Self {
+        match self.real_implementation {
+            Some(ref real_implementation) => {
+                self.call.return_value = Some(real_implementation.clone());
+            }
+            None => panic!(
+                "The call {} can't call through, because no real implementation is available.\n\
+                 Real implementations are passed to the mock using `with_fallback`.",
+                *self.call.matcher
+            ),
+        }
         self
     }
 
@@ -95,84 +250,157 @@ where
     {
         self.call.expected_calls = expected_calls.into();
         self.assert_times_and_return_value_are_compatible();
+
+        if let Some(ref sequence_step) = self.call.sequence_step {
+            sequence_step.set_expected_calls(self.call.expected_calls.clone());
+        }
+
+        self
+    }
+
+    /// Adds this call to a [`Sequence`].
+    /// The calls of a sequence must be made in the order in which they were added,
+    /// even if they belong to different methods or mocks.
+    ///
+    /// # Panics
+    /// Panics if this call is already part of a sequence.
+    pub fn in_sequence(&mut self, sequence: &Sequence) -> &mut Self {
+        if self.call.sequence_step.is_some() {
+            panic!(
+                "The call {} is already part of a sequence.",
+                *self.call.matcher
+            );
+        }
+
+        // The matcher is displayed as `method(arguments)`,
+        // the step is described using the qualified name instead, e.g. `FileMock::method(arguments)`.
+        let matcher = self.call.matcher.to_string();
+        let arguments = matcher.find('(').map_or("", |index| &matcher[index..]);
+
+        self.call.sequence_step = Some(sequence.add_step(
+            format!("{}{}", self.method_name, arguments),
+            self.call.expected_calls.clone(),
+        ));
         self
     }
 
-    pub(crate) fn new(call: &'a mut MethodCall<'mock, A, R>) -> Self {
-        Self { call }
+    pub(crate) fn new(
+        method_name: &'a str,
+        call: &'a mut MethodCall<'mock, A, R, G>,
+        real_implementation: Option<Shared<G, G>>,
+    ) -> Self {
+        Self {
+            method_name,
+            call,
+            real_implementation,
+        }
     }
 
     fn assert_times_and_return_value_are_compatible(&self) {
-        let one_expected_call = ExpectedCalls::from(1);
-        let returns_only_once = self
-            .call
-            .return_value
-            .as_ref()
-            .map(|r| !r.can_return_more_than_once())
-            .unwrap_or_default();
+        let return_value = match self.call.return_value {
+            Some(ref return_value) => return_value,
+            None => return,
+        };
         let expected_calls = &self.call.expected_calls;
-        if returns_only_once && expected_calls != &one_expected_call {
-            panic!(
-                "Return value can only be returned once but call was expected {}.",
-                expected_calls
-            );
+
+        if !return_value.can_return_more_than_once() {
+            if expected_calls != &ExpectedCalls::from(1) {
+                panic!(
+                    "Return value can only be returned once but call was expected {}.",
+                    expected_calls
+                );
+            }
+        } else if let Some(max_number_of_return_values) = return_value.max_number_of_return_values()
+        {
+            // Without an upper limit, the method panics once all return values were returned.
+            let fits_into_return_values = match expected_calls.max_value() {
+                Some(max_value) => max_value <= max_number_of_return_values,
+                None => expected_calls.min_value() <= max_number_of_return_values,
+            };
+
+            if !fits_into_return_values {
+                panic!(
+                    "Return value can only be returned {} but call was expected {}.",
+                    DisplayTimes(max_number_of_return_values),
+                    expected_calls
+                );
+            }
         }
     }
 }
 
-pub(crate) struct MethodCall<'mock, A, R> {
+pub(crate) struct MethodCall<'mock, A, R, G = dyn ReturnValueGenerator<A, R> + 'mock>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
+{
     expected_calls: ExpectedCalls,
-    actual_number_of_calls: RefCell<u64>,
-    matcher: Rc<A>,
-    return_value: Option<Rc<dyn ReturnValueGenerator<A, R> + 'mock>>,
+    actual_number_of_calls: Cell<G, u64>,
+    matcher: Shared<G, A>,
+    return_value: Option<Shared<G, G>>,
+    sequence_step: Option<SequenceStep>,
+    phantom_data: PhantomData<fn() -> (&'mock (), R)>,
 }
 
-impl<'mock, A, R> Debug for MethodCall<'mock, A, R>
+impl<'mock, A, R, G> Debug for MethodCall<'mock, A, R, G>
 where
-    A: Debug,
+    A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type MethodCall<'mock, A, R>))
+        f.debug_struct(name_of!(type MethodCall<'mock, A, R, G>))
             .field(name_of!(expected_calls in Self), &self.expected_calls)
             .field(
                 name_of!(actual_number_of_calls in Self),
-                &self.actual_number_of_calls,
+                &self.number_of_calls(),
+            )
+            .field(name_of!(matcher in Self), &*self.matcher)
+            .field(
+                name_of!(return_value in Self),
+                &self.return_value.as_deref(),
             )
-            .field(name_of!(matcher in Self), &self.matcher)
-            .field(name_of!(return_value in Self), &self.return_value)
+            .field(name_of!(sequence_step in Self), &self.sequence_step)
             .finish()
     }
 }
 
-impl<'mock, A, R> Clone for MethodCall<'mock, A, R>
+impl<'mock, A, R, G> Clone for MethodCall<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn clone(&self) -> Self {
         Self {
             expected_calls: self.expected_calls.clone(),
-            actual_number_of_calls: self.actual_number_of_calls.clone(),
+            actual_number_of_calls: StorageCell::new(self.number_of_calls()),
             matcher: self.matcher.clone(),
             return_value: self.return_value.clone(),
+            sequence_step: self.sequence_step.clone(),
+            phantom_data: PhantomData,
         }
     }
 }
 
-impl<'mock, A, R> MethodCall<'mock, A, R>
+impl<'mock, A, R, G> MethodCall<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     pub(crate) fn new(matcher: A) -> Self {
         Self {
             expected_calls: ExpectedCalls::default(),
-            actual_number_of_calls: RefCell::default(),
-            matcher: Rc::new(matcher),
-            return_value: R::default_return_value(),
+            actual_number_of_calls: StorageCell::new(0),
+            matcher: storage::share::<G, _>(matcher),
+            return_value: <R as DefaultReturnValue<A, G>>::default_return_value(),
+            sequence_step: None,
+            phantom_data: PhantomData,
         }
     }
 
     pub(crate) fn call(&self, arguments: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-        *self.actual_number_of_calls.borrow_mut() += 1;
+        self.matcher.arguments_accepted(&arguments);
+        self.actual_number_of_calls
+            .with(|number_of_calls| *number_of_calls += 1);
 
         match self.return_value {
             Some(ref return_value) => return_value.generate_return_value(arguments),
@@ -181,12 +409,11 @@ where
     }
 
     pub(crate) fn was_called_expected_number_of_times(&self) -> bool {
-        self.expected_calls
-            .contains(*self.actual_number_of_calls.borrow())
+        self.expected_calls.contains(self.number_of_calls())
     }
 
     pub(crate) fn accepts_more_calls(&self) -> bool {
-        let number_of_calls = *self.actual_number_of_calls.borrow();
+        let number_of_calls = self.number_of_calls();
         match self.expected_calls.max_value() {
             Some(max_value) => number_of_calls < max_value,
             None => true,
@@ -199,21 +426,37 @@ where
     ) -> bool {
         self.matcher.matches_arguments(arguments)
     }
+
+    /// Records the call in the sequence that this call is part of.
+    pub(crate) fn enter_sequence(&self) -> Result<(), SequenceError> {
+        match self.sequence_step {
+            Some(ref sequence_step) => sequence_step.enter(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn report(&self) -> ExpectedCallReport {
+        ExpectedCallReport::new(
+            self.matcher.to_string(),
+            DisplayOption(self.return_value.as_deref()).to_string(),
+            self.expected_calls.clone(),
+            self.number_of_calls(),
+        )
+    }
+
+    fn number_of_calls(&self) -> u64 {
+        self.actual_number_of_calls
+            .with(|number_of_calls| *number_of_calls)
+    }
 }
 
-impl<'mock, A, R> Display for MethodCall<'mock, A, R>
+impl<'mock, A, R, G> Display for MethodCall<'mock, A, R, G>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    G: ReturnValueGenerator<A, R> + UsesStorage + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} -> {} {}, was called {}",
-            self.matcher,
-            DisplayOption(self.return_value.as_ref()),
-            self.expected_calls,
-            DisplayTimes(*self.actual_number_of_calls.borrow())
-        )
+        write!(f, "{}", self.report())
     }
 }
 
@@ -222,8 +465,9 @@ mod test {
     use super::*;
     use crate::arguments::ArgumentsMock;
     use crate::matcher::ArgumentsMatcherMock;
-    use std::cell::RefCell;
     use std::fmt::Debug;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::thread::panicking;
 
     #[derive(Debug)]
@@ -231,7 +475,7 @@ mod test {
     where
         R: Clone + Debug,
     {
-        generate_return_value_was_called: RefCell<bool>,
+        generate_return_value_was_called: AtomicBool,
         return_value: Option<R>,
     }
 
@@ -242,7 +486,7 @@ mod test {
         fn new(return_value: Option<R>) -> Self {
             Self {
                 return_value,
-                generate_return_value_was_called: RefCell::default(),
+                generate_return_value_was_called: AtomicBool::default(),
             }
         }
     }
@@ -262,7 +506,8 @@ mod test {
         R: Clone + Debug,
     {
         fn generate_return_value(&self, _input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-            *self.generate_return_value_was_called.borrow_mut() = true;
+            self.generate_return_value_was_called
+                .store(true, Ordering::SeqCst);
 
             self.return_value
                 .as_ref()
@@ -278,7 +523,7 @@ mod test {
         fn drop(&mut self) {
             if !panicking() && self.return_value.is_some() {
                 assert!(
-                    *self.generate_return_value_was_called.borrow(),
+                    self.generate_return_value_was_called.load(Ordering::SeqCst),
                     "generate_return_value_was_called() was never called"
                 );
             }
@@ -323,7 +568,7 @@ mod test {
     #[test]
     fn was_called_expected_number_of_times_returns_false() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(None));
+            let mut call: MethodCall<'_, _, ()> = MethodCall::new(ArgumentsMatcherMock::new(None));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(Some(()))));
             call.expected_calls = (2..).into();
             call
@@ -337,7 +582,8 @@ mod test {
     #[test]
     fn matches_expected_arguments_returns_true() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(true)));
+            let mut call: MethodCall<'_, _, ()> =
+                MethodCall::new(ArgumentsMatcherMock::new(Some(true)));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
             call
         };
@@ -348,7 +594,8 @@ mod test {
     #[test]
     fn matches_expected_arguments_returns_false() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(false)));
+            let mut call: MethodCall<'_, _, ()> =
+                MethodCall::new(ArgumentsMatcherMock::new(Some(false)));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
             call
         };
@@ -0,0 +1,324 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// An argument of a call that was recorded by a mock, see [`CallHistory`](crate::CallHistory).
+///
+/// Arguments implementing [`Clone`] are recorded as a clone.
+/// References are recorded using [`ToOwned`], e.g. a `&str` argument is recorded as a [`String`]
+/// and dereferences to `str`. Arguments that can be neither cloned nor converted into an owned value
+/// (or that are not [`Send`] and [`Sync`] in a mock generated with `#[mockable(sync)]`) are not recorded.
+///
+/// `E` is the type-erased value that is stored, which only differs from the default for thread-safe mocks.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+/// greeter
+///     .expect_greet(|arg| arg.any())
+///     .returns(String::from("Hello"));
+///
+/// greeter.greet("Jane");
+///
+/// let calls = greeter.calls_to_greet();
+/// let name = &calls.last().unwrap().arguments().name;
+///
+/// assert_eq!(Some("Jane"), name.get());
+/// assert_eq!(4, name.len());
+/// ```
+pub struct Recorded<T, E = dyn Borrow<T>>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    value: Option<Arc<E>>,
+    phantom_data: PhantomData<fn(&T)>,
+}
+
+impl<T, E> Recorded<T, E>
+where
+    T: ?Sized,
+    E: Borrow<T> + ?Sized,
+{
+    /// Returns the recorded value or `None` if the argument was not recorded.
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_deref().map(Borrow::borrow)
+    }
+
+    /// Returns `true` if the argument was recorded.
+    pub fn is_recorded(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+impl<T, E> Recorded<T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    fn new(value: Arc<E>) -> Self {
+        Self {
+            value: Some(value),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn not_recorded() -> Self {
+        Self {
+            value: None,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Deref for Recorded<T, E>
+where
+    T: ?Sized,
+    E: Borrow<T> + ?Sized,
+{
+    type Target = T;
+
+    /// # Panics
+    /// Panics if the argument was not recorded.
+    fn deref(&self) -> &T {
+        self.get().unwrap_or_else(|| {
+            panic!(
+                "The argument was not recorded, because it can't be cloned or converted into an owned value."
+            )
+        })
+    }
+}
+
+impl<T, E> Clone for Recorded<T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Debug for Recorded<T, E>
+where
+    T: Debug + ?Sized,
+    E: Borrow<T> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => Debug::fmt(value, f),
+            None => write!(f, "<not recorded>"),
+        }
+    }
+}
+
+/// Converts a recorded value into the type-erased value that is stored by [`Recorded`].
+/// Thread-safe mocks store values that are [`Send`] and [`Sync`], which is enforced by this conversion.
+pub trait FromRecordedValue<T, V>
+where
+    T: ?Sized,
+{
+    /// Type-erases the given value.
+    fn from_recorded_value(value: V) -> Arc<Self>;
+}
+
+impl<T, V> FromRecordedValue<T, V> for dyn Borrow<T>
+where
+    T: ?Sized,
+    V: Borrow<T> + 'static,
+{
+    fn from_recorded_value(value: V) -> Arc<Self> {
+        Arc::new(value)
+    }
+}
+
+impl<T, V> FromRecordedValue<T, V> for dyn Borrow<T> + Send + Sync
+where
+    T: ?Sized,
+    V: Borrow<T> + Send + Sync + 'static,
+{
+    fn from_recorded_value(value: V) -> Arc<Self> {
+        Arc::new(value)
+    }
+}
+
+// The generated mocks record their arguments using autoref-based specialization:
+// `(&RecordValue::<T, E>::new(&argument)).record()` resolves to `RecordClonedValue::record`
+// if the argument can be recorded and to `RecordNothing::record` otherwise.
+// `E` needs to be known when the method is resolved, which is why it is always specified.
+
+/// Records an argument that is passed by value.
+#[derive(Debug)]
+pub struct RecordValue<'a, T, E>
+where
+    E: ?Sized,
+{
+    value: &'a T,
+    phantom_data: PhantomData<fn() -> Arc<E>>,
+}
+
+impl<'a, T, E> RecordValue<'a, T, E>
+where
+    E: ?Sized,
+{
+    /// Creates a recorder for the given argument.
+    pub fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Records an argument that is passed by reference.
+#[derive(Debug)]
+pub struct RecordReference<'a, T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    value: &'a T,
+    phantom_data: PhantomData<fn() -> Arc<E>>,
+}
+
+impl<'a, T, E> RecordReference<'a, T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    /// Creates a recorder for the referenced argument.
+    pub fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Records a clone of an argument that is passed by value.
+pub trait RecordClonedValue<T, E>
+where
+    E: ?Sized,
+{
+    /// Records the argument.
+    fn record(&self) -> Recorded<T, E>;
+}
+
+impl<'a, T, E> RecordClonedValue<T, E> for RecordValue<'a, T, E>
+where
+    T: Clone,
+    E: FromRecordedValue<T, T> + ?Sized,
+{
+    fn record(&self) -> Recorded<T, E> {
+        Recorded::new(E::from_recorded_value(self.value.clone()))
+    }
+}
+
+/// Records the owned value of an argument that is passed by reference.
+pub trait RecordOwnedReference<T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    /// Records the argument.
+    fn record(&self) -> Recorded<T, E>;
+}
+
+impl<'a, T, E> RecordOwnedReference<T, E> for RecordReference<'a, T, E>
+where
+    T: ToOwned + ?Sized,
+    E: FromRecordedValue<T, T::Owned> + ?Sized,
+{
+    fn record(&self) -> Recorded<T, E> {
+        Recorded::new(E::from_recorded_value(self.value.to_owned()))
+    }
+}
+
+/// Used for arguments that can't be recorded.
+pub trait RecordNothing<T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    /// Returns an argument that was not recorded.
+    fn record(&self) -> Recorded<T, E> {
+        Recorded::not_recorded()
+    }
+}
+
+impl<'a, T, E> RecordNothing<T, E> for &RecordValue<'a, T, E> where E: ?Sized {}
+
+impl<'a, T, E> RecordNothing<T, E> for &RecordReference<'a, T, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+}
+
+// The borrows select the recorder implementation and are therefore not needless.
+#[allow(clippy::needless_borrow)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct NotClone;
+
+    #[test]
+    fn cloneable_values_are_recorded() {
+        let recorded: Recorded<u32> = (&RecordValue::<_, dyn Borrow<u32>>::new(&42)).record();
+
+        assert_eq!(Some(&42), recorded.get());
+    }
+
+    #[test]
+    fn references_are_recorded_as_owned_values() {
+        let recorded: Recorded<str> = (&RecordReference::<_, dyn Borrow<str>>::new("foo")).record();
+
+        assert_eq!(Some("foo"), recorded.get());
+        assert_eq!(3, recorded.len());
+    }
+
+    #[test]
+    fn values_that_are_not_cloneable_are_not_recorded() {
+        let recorded: Recorded<NotClone> =
+            (&RecordValue::<_, dyn Borrow<NotClone>>::new(&NotClone)).record();
+
+        assert!(!recorded.is_recorded());
+        assert_eq!("<not recorded>", format!("{:?}", recorded));
+    }
+
+    #[test]
+    fn values_that_are_not_thread_safe_are_not_recorded_for_thread_safe_mocks() {
+        let value = Rc::new(42);
+        let recorded: Recorded<Rc<u32>, dyn Borrow<Rc<u32>> + Send + Sync> =
+            (&RecordValue::<_, dyn Borrow<Rc<u32>> + Send + Sync>::new(&value)).record();
+
+        assert!(!recorded.is_recorded());
+    }
+
+    #[test]
+    #[should_panic(expected = "The argument was not recorded")]
+    fn deref_panics_if_argument_was_not_recorded() {
+        let recorded: Recorded<NotClone> =
+            (&RecordValue::<_, dyn Borrow<NotClone>>::new(&NotClone)).record();
+
+        let _value: &NotClone = &recorded;
+    }
+}
@@ -1,21 +1,87 @@
+pub(crate) use self::calls_through::*;
 pub(crate) use self::cloned::*;
+pub(crate) use self::closure::*;
+pub(crate) use self::default_value::*;
 pub(crate) use self::once::*;
 pub(crate) use self::panic::*;
+pub(crate) use self::successive::*;
 
 use crate::matcher::ArgumentsMatcher;
+use crate::storage::{Shared, SingleThreadedStorage, ThreadSafeStorage, UsesStorage};
 use std::fmt::{Debug, Display};
+use std::rc::Rc;
+use std::sync::Arc;
 
+mod calls_through;
 mod cloned;
+mod closure;
+mod default_value;
 mod once;
 mod panic;
+mod successive;
 
-pub(crate) trait ReturnValueGenerator<A, R>: Display + Debug
+/// Generates the return value of an expected call.
+pub trait ReturnValueGenerator<A, R>: Display + Debug
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
+    /// Generates the return value for a call with the given arguments.
     fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R;
 
+    /// Whether more than one value can be generated.
+    /// If not, the call must be expected exactly once.
     fn can_return_more_than_once(&self) -> bool {
         true
     }
+
+    /// The maximum amount of values that can be generated,
+    /// or [`None`] if there is no upper limit.
+    fn max_number_of_return_values(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Converts a [`ReturnValueGenerator`] into the type-erased generator
+/// that is stored by a mocked method.
+///
+/// Thread-safe mocks store generators that are [`Send`] and [`Sync`],
+/// which is enforced by this conversion.
+pub trait FromReturnValueGenerator<T>: UsesStorage {
+    /// Type-erases the given generator.
+    fn from_return_value_generator(generator: T) -> Shared<Self, Self>;
+}
+
+impl<'mock, A, R> UsesStorage for dyn ReturnValueGenerator<A, R> + 'mock
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    type Storage = SingleThreadedStorage;
+}
+
+impl<'mock, A, R> UsesStorage for dyn ReturnValueGenerator<A, R> + Send + Sync + 'mock
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    type Storage = ThreadSafeStorage;
+}
+
+impl<'mock, A, R, T> FromReturnValueGenerator<T> for dyn ReturnValueGenerator<A, R> + 'mock
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ReturnValueGenerator<A, R> + 'mock,
+{
+    fn from_return_value_generator(generator: T) -> Shared<Self, Self> {
+        Rc::new(generator)
+    }
+}
+
+impl<'mock, A, R, T> FromReturnValueGenerator<T>
+    for dyn ReturnValueGenerator<A, R> + Send + Sync + 'mock
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ReturnValueGenerator<A, R> + Send + Sync + 'mock,
+{
+    fn from_return_value_generator(generator: T) -> Shared<Self, Self> {
+        Arc::new(generator)
+    }
 }
@@ -0,0 +1,59 @@
+use super::ReturnValueGenerator;
+use crate::matcher::ArgumentsMatcher;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+/// Forwards the call to the real implementation that was passed to `with_fallback`.
+pub struct CallsThrough<F>(pub(crate) F);
+
+impl<A, R, F> ReturnValueGenerator<A, R> for CallsThrough<F>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R,
+{
+    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        (self.0)(input)
+    }
+}
+
+impl<F> Display for CallsThrough<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<calls through>")
+    }
+}
+
+impl<F> Debug for CallsThrough<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(name_of!(type CallsThrough<F>))
+            .field(&format_args!("<closure>"))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn returns_value_of_real_implementation() {
+        let calls_through = CallsThrough(|_: ArgumentsMock| String::from("foo"));
+
+        assert_eq!(
+            String::from("foo"),
+            ReturnValueGenerator::<ArgumentsMatcherMock, String>::generate_return_value(
+                &calls_through,
+                ArgumentsMock
+            )
+        );
+    }
+
+    #[test]
+    fn is_displayed_as_calls_through() {
+        assert_eq!(
+            "<calls through>",
+            CallsThrough(|_: ArgumentsMock| ()).to_string()
+        );
+    }
+}
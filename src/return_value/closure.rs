@@ -0,0 +1,50 @@
+use super::ReturnValueGenerator;
+use crate::matcher::ArgumentsMatcher;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+pub struct Closure<F>(pub(crate) F);
+
+impl<A, R, F> ReturnValueGenerator<A, R> for Closure<F>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R,
+{
+    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        (self.0)(input)
+    }
+}
+
+impl<F> Display for Closure<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<closure>")
+    }
+}
+
+impl<F> Debug for Closure<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(name_of!(type Closure<F>))
+            .field(&format_args!("<closure>"))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn returns_value_computed_by_closure() {
+        let closure = Closure(|_: ArgumentsMock| String::from("foo"));
+
+        assert_eq!(
+            String::from("foo"),
+            ReturnValueGenerator::<ArgumentsMatcherMock, String>::generate_return_value(
+                &closure,
+                ArgumentsMock
+            )
+        );
+    }
+}
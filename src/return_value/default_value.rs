@@ -0,0 +1,46 @@
+use super::ReturnValueGenerator;
+use crate::matcher::ArgumentsMatcher;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+pub struct DefaultValue;
+
+impl<A, R> ReturnValueGenerator<A, R> for DefaultValue
+where
+    R: Default,
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        R::default()
+    }
+}
+
+impl Display for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<default>")
+    }
+}
+
+impl Debug for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name_of!(type DefaultValue))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn returns_default_value() {
+        assert_eq!(
+            String::new(),
+            ReturnValueGenerator::<ArgumentsMatcherMock, String>::generate_return_value(
+                &DefaultValue,
+                ArgumentsMock
+            )
+        );
+    }
+}
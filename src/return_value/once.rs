@@ -1,25 +1,30 @@
 use super::ReturnValueGenerator;
 use crate::fmt::MaybeDebug;
 use crate::matcher::ArgumentsMatcher;
-use std::cell::RefCell;
+use crate::storage::{Storage, StorageCell};
 use std::fmt::{self, Debug, Display};
 
-pub(crate) struct Once<T>(RefCell<Option<T>>);
+pub struct Once<T, S>(S::Cell<Option<T>>)
+where
+    S: Storage;
 
-impl<T> Once<T> {
+impl<T, S> Once<T, S>
+where
+    S: Storage,
+{
     pub(crate) fn new(value: T) -> Self {
-        Self(RefCell::new(Some(value)))
+        Self(StorageCell::new(Some(value)))
     }
 }
 
-impl<A, R> ReturnValueGenerator<A, R> for Once<R>
+impl<A, R, S> ReturnValueGenerator<A, R> for Once<R, S>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    S: Storage,
 {
     fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
         self.0
-            .borrow_mut()
-            .take()
+            .with(Option::take)
             .expect("This value was already returned")
     }
 
@@ -28,21 +33,23 @@ where
     }
 }
 
-impl<R> Display for Once<R>
+impl<R, S> Display for Once<R, S>
 where
     R: MaybeDebug,
+    S: Storage,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        self.0.with(|value| MaybeDebug::fmt(value, f))
     }
 }
 
-impl<R> Debug for Once<R>
+impl<R, S> Debug for Once<R, S>
 where
     R: MaybeDebug,
+    S: Storage,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        self.0.with(|value| MaybeDebug::fmt(value, f))
     }
 }
 
@@ -51,10 +58,11 @@ mod test {
     use super::*;
     use crate::arguments::ArgumentsMock;
     use crate::matcher::ArgumentsMatcherMock;
+    use crate::storage::{SingleThreadedStorage, ThreadSafeStorage};
 
     #[test]
     fn returns_expected_value() {
-        let return_value = Once::new(String::from("foo"));
+        let return_value = Once::<_, SingleThreadedStorage>::new(String::from("foo"));
 
         assert_eq!(
             String::from("foo"),
@@ -68,7 +76,7 @@ mod test {
     #[test]
     #[should_panic]
     fn panics_when_called_more_than_once() {
-        let return_value = Once::new(String::from("foo"));
+        let return_value = Once::<_, ThreadSafeStorage>::new(String::from("foo"));
 
         assert_eq!(
             String::from("foo"),
@@ -0,0 +1,137 @@
+use super::ReturnValueGenerator;
+use crate::fmt::MaybeDebugWrapper;
+use crate::matcher::ArgumentsMatcher;
+use crate::storage::{Storage, StorageCell};
+use nameof::name_of;
+use std::collections::VecDeque;
+use std::fmt::{self, Debug, Display};
+
+pub struct Successive<T, S>
+where
+    S: Storage,
+{
+    values: S::Cell<VecDeque<T>>,
+    number_of_values: u64,
+}
+
+impl<T, S> Successive<T, S>
+where
+    S: Storage,
+{
+    pub(crate) fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let values: VecDeque<_> = values.into_iter().collect();
+        let number_of_values = values.len() as u64;
+
+        Self {
+            values: StorageCell::new(values),
+            number_of_values,
+        }
+    }
+}
+
+impl<A, R, S> ReturnValueGenerator<A, R> for Successive<R, S>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    S: Storage,
+{
+    fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        self.values
+            .with(|values| values.pop_front())
+            .expect("All values of the sequence were already returned")
+    }
+
+    fn max_number_of_return_values(&self) -> Option<u64> {
+        Some(self.number_of_values)
+    }
+}
+
+impl<R, S> Display for Successive<R, S>
+where
+    S: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.values
+            .with(|values| write!(f, "{:?} in sequence", MaybeDebugWrapper(values)))
+    }
+}
+
+impl<R, S> Debug for Successive<R, S>
+where
+    S: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.values.with(|values| {
+            f.debug_struct(name_of!(type Successive<R, S>))
+                .field(name_of!(values in Self), &MaybeDebugWrapper(values))
+                .field(name_of!(number_of_values in Self), &self.number_of_values)
+                .finish()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+    use crate::storage::{SingleThreadedStorage, ThreadSafeStorage};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn returns_values_in_order() {
+        let return_value = Successive::<_, SingleThreadedStorage>::new(vec!["foo", "bar", "baz"]);
+
+        for expected in &["foo", "bar", "baz"] {
+            assert_eq!(
+                *expected,
+                ReturnValueGenerator::<ArgumentsMatcherMock, _>::generate_return_value(
+                    &return_value,
+                    ArgumentsMock
+                )
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "All values of the sequence were already returned")]
+    fn panics_when_called_more_often_than_there_are_values() {
+        let return_value = Successive::<_, ThreadSafeStorage>::new(vec![String::from("foo")]);
+
+        ReturnValueGenerator::<ArgumentsMatcherMock, _>::generate_return_value(
+            &return_value,
+            ArgumentsMock,
+        );
+        ReturnValueGenerator::<ArgumentsMatcherMock, _>::generate_return_value(
+            &return_value,
+            ArgumentsMock,
+        );
+    }
+
+    #[test]
+    fn can_be_displayed_after_all_values_were_returned() {
+        let return_value = Successive::<_, ThreadSafeStorage>::new(Vec::<String>::new());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            ReturnValueGenerator::<ArgumentsMatcherMock, _>::generate_return_value(
+                &return_value,
+                ArgumentsMock,
+            )
+        }));
+
+        assert!(result.is_err());
+        assert!(!return_value.values.is_poisoned());
+        assert!(return_value.to_string().ends_with(" in sequence"));
+    }
+
+    #[test]
+    fn max_number_of_return_values_is_number_of_values() {
+        let return_value = Successive::<_, SingleThreadedStorage>::new(vec![1, 2, 3]);
+
+        assert_eq!(
+            Some(3),
+            ReturnValueGenerator::<ArgumentsMatcherMock, i32>::max_number_of_return_values(
+                &return_value
+            )
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use crate::expected_calls::ExpectedCalls;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Defines an order for expected calls across methods and mocks.
+///
+/// Expected calls join a sequence using [`MethodCallBuilder::in_sequence`].
+/// The calls must then be made in the same order in which they joined the sequence.
+/// An expected call that is expected more than once must receive all of its calls
+/// before the sequence can move on to the next call.
+///
+/// [`MethodCallBuilder::in_sequence`]: crate::MethodCallBuilder::in_sequence
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Sequence};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait File {
+///     fn open(&self);
+///
+///     fn write(&self, content: &str);
+///
+///     fn close(&self);
+/// }
+///
+/// let sequence = Sequence::new();
+/// let mut file = FileMock::new();
+///
+/// file.expect_open().in_sequence(&sequence).returns(());
+/// file.expect_write(|arg| arg.any())
+///     .times(2)
+///     .in_sequence(&sequence)
+///     .returns(());
+/// file.expect_close().in_sequence(&sequence).returns(());
+///
+/// file.open();
+/// file.write("Hello");
+/// file.write("World");
+/// file.close();
+/// ```
+#[derive(Default)]
+pub struct Sequence {
+    state: Arc<Mutex<SequenceState>>,
+}
+
+impl Sequence {
+    /// Creates a new sequence without any expected calls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn add_step(
+        &self,
+        description: String,
+        expected_calls: ExpectedCalls,
+    ) -> SequenceStep {
+        let mut state = lock(&self.state);
+
+        state.steps.push(StepState {
+            description,
+            expected_calls,
+            actual_number_of_calls: 0,
+        });
+
+        SequenceStep {
+            state: self.state.clone(),
+            position: state.steps.len() - 1,
+        }
+    }
+}
+
+impl Debug for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type Sequence))
+            .field(name_of!(state in Self), &*lock(&self.state))
+            .finish()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SequenceState {
+    steps: Vec<StepState>,
+    current_position: usize,
+}
+
+#[derive(Debug)]
+struct StepState {
+    description: String,
+    expected_calls: ExpectedCalls,
+    actual_number_of_calls: u64,
+}
+
+impl StepState {
+    fn is_satisfied(&self) -> bool {
+        self.expected_calls.contains(self.actual_number_of_calls)
+    }
+}
+
+/// The position of an expected call within a [`Sequence`].
+#[derive(Clone)]
+pub(crate) struct SequenceStep {
+    state: Arc<Mutex<SequenceState>>,
+    position: usize,
+}
+
+impl SequenceStep {
+    pub(crate) fn set_expected_calls(&self, expected_calls: ExpectedCalls) {
+        lock(&self.state).steps[self.position].expected_calls = expected_calls;
+    }
+
+    /// Records a call to this step. Fails if the call is not in the expected order,
+    /// i.e. if a later step was already called or if a preceding step is not yet satisfied.
+    pub(crate) fn enter(&self) -> Result<(), SequenceError> {
+        let mut state = lock(&self.state);
+        let current_position = state.current_position;
+
+        let expected_position = if self.position < current_position {
+            Some(current_position)
+        } else {
+            (current_position..self.position)
+                .find(|&position| !state.steps[position].is_satisfied())
+        };
+
+        if let Some(expected_position) = expected_position {
+            return Err(SequenceError {
+                expected_position,
+                expected_description: state.steps[expected_position].description.clone(),
+                actual_position: self.position,
+            });
+        }
+
+        state.current_position = self.position;
+        state.steps[self.position].actual_number_of_calls += 1;
+
+        Ok(())
+    }
+}
+
+impl Debug for SequenceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type SequenceStep))
+            .field(name_of!(position in Self), &self.position)
+            .finish()
+    }
+}
+
+/// A call was made that is not in the order defined by a [`Sequence`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SequenceError {
+    expected_position: usize,
+    expected_description: String,
+    actual_position: usize,
+}
+
+impl SequenceError {
+    /// The position of the call that was expected next, starting at zero.
+    pub fn expected_position(&self) -> usize {
+        self.expected_position
+    }
+
+    /// The method and the argument matchers of the call that was expected next.
+    pub fn expected_description(&self) -> &str {
+        &self.expected_description
+    }
+
+    /// The position of the call that was actually made, starting at zero.
+    pub fn actual_position(&self) -> usize {
+        self.actual_position
+    }
+}
+
+impl Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Expected call #{} of the sequence: {}",
+            self.expected_position + 1,
+            self.expected_description
+        )?;
+        write!(
+            f,
+            "Actual call #{} of the sequence",
+            self.actual_position + 1
+        )
+    }
+}
+
+/// The state is never left inconsistent while the lock is held,
+/// so it's safe to continue using it after a panic in another thread.
+fn lock(state: &Mutex<SequenceState>) -> MutexGuard<'_, SequenceState> {
+    state.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn calls_in_order_are_accepted() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first()"), ExpectedCalls::default());
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        assert!(first.enter().is_ok());
+        assert!(second.enter().is_ok());
+    }
+
+    #[test]
+    fn call_errors_if_preceding_step_is_not_satisfied() {
+        let sequence = Sequence::new();
+        let _first = sequence.add_step(String::from("first()"), ExpectedCalls::default());
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        let error = second.enter().unwrap_err();
+
+        assert_eq!(0, error.expected_position);
+        assert_eq!("first()", error.expected_description);
+        assert_eq!(1, error.actual_position);
+    }
+
+    #[test]
+    fn call_errors_if_later_step_was_already_called() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first()"), ExpectedCalls::from(1..));
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        first.enter().unwrap();
+        second.enter().unwrap();
+
+        let error = first.enter().unwrap_err();
+
+        assert_eq!(1, error.expected_position);
+        assert_eq!(0, error.actual_position);
+    }
+
+    #[test]
+    fn step_can_be_called_repeatedly() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first()"), ExpectedCalls::from(2));
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        first.enter().unwrap();
+        assert!(second.enter().is_err());
+        first.enter().unwrap();
+        assert!(second.enter().is_ok());
+    }
+
+    #[test]
+    fn optional_steps_can_be_skipped() {
+        let sequence = Sequence::new();
+        let _first = sequence.add_step(String::from("first()"), ExpectedCalls::from(..));
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        assert!(second.enter().is_ok());
+    }
+
+    #[test]
+    fn updated_expected_calls_are_used() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first()"), ExpectedCalls::default());
+        let second = sequence.add_step(String::from("second()"), ExpectedCalls::default());
+
+        first.set_expected_calls(ExpectedCalls::from(0));
+
+        assert!(second.enter().is_ok());
+    }
+}
@@ -0,0 +1,180 @@
+use nameof::name_of;
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::fmt::{self, Debug};
+use std::rc::Rc;
+use std::thread::panicking;
+
+/// The expected calls to the methods of a mocked trait that don't take `self`.
+pub trait StaticExpectations: Any {
+    /// Panics if the expected calls were not satisfied.
+    fn verify_unwrap(&self);
+}
+
+thread_local! {
+    static REGISTERED_EXPECTATIONS: RefCell<Vec<Rc<dyn Any>>> = RefCell::new(Vec::new());
+}
+
+/// Calls `f` with the expectations of type `S` that are registered on the current thread.
+///
+/// # Panics
+/// Panics if no expectations of type `S` are registered on the current thread.
+pub fn with_static_expectations<S, F, R>(method_name: &'static str, f: F) -> R
+where
+    S: StaticExpectations,
+    F: FnOnce(&S) -> R,
+{
+    // The registry is not borrowed while `f` is running,
+    // so that return value generators are free to call other static methods.
+    let expectations = REGISTERED_EXPECTATIONS.with(|registered_expectations| {
+        registered_expectations
+            .borrow()
+            .iter()
+            .find_map(|expectations| expectations.clone().downcast::<S>().ok())
+    });
+
+    match expectations {
+        Some(expectations) => f(&expectations),
+        None => panic!(
+            "\n\nThe call to {} was not expected, because no expectations of type {} are registered on this thread.\n\
+             Expectations for methods without `self` need to be registered using `register()`.\n",
+            method_name,
+            type_name::<S>()
+        ),
+    }
+}
+
+/// Keeps expectations for methods without `self` registered on the current thread.
+///
+/// The expectations are unregistered and the expected calls are verified
+/// when the guard goes out of scope.
+#[must_use = "The expectations are unregistered as soon as the guard is dropped"]
+pub struct StaticExpectationsGuard<S>
+where
+    S: StaticExpectations,
+{
+    expectations: Rc<S>,
+}
+
+impl<S> StaticExpectationsGuard<S>
+where
+    S: StaticExpectations,
+{
+    /// Registers the expectations on the current thread.
+    ///
+    /// # Panics
+    /// Panics if expectations of the same type are already registered on the current thread.
+    #[doc(hidden)]
+    pub fn internal_register(expectations: S) -> Self {
+        let expectations = Rc::new(expectations);
+
+        REGISTERED_EXPECTATIONS.with(|registered_expectations| {
+            let mut registered_expectations = registered_expectations.borrow_mut();
+
+            if registered_expectations
+                .iter()
+                .any(|registered| registered.is::<S>())
+            {
+                panic!(
+                    "\n\nExpectations of type {} are already registered on this thread.\n",
+                    type_name::<S>()
+                );
+            }
+
+            registered_expectations.push(expectations.clone());
+        });
+
+        Self { expectations }
+    }
+}
+
+impl<S> Debug for StaticExpectationsGuard<S>
+where
+    S: StaticExpectations + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type StaticExpectationsGuard<S>))
+            .field(name_of!(expectations in Self), &self.expectations)
+            .finish()
+    }
+}
+
+impl<S> Drop for StaticExpectationsGuard<S>
+where
+    S: StaticExpectations,
+{
+    fn drop(&mut self) {
+        REGISTERED_EXPECTATIONS.with(|registered_expectations| {
+            registered_expectations
+                .borrow_mut()
+                .retain(|registered| !registered.is::<S>())
+        });
+
+        if !panicking() {
+            self.expectations.verify_unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Debug, Default)]
+    struct ExpectationsMock {
+        was_verified: Rc<Cell<bool>>,
+    }
+
+    impl StaticExpectations for ExpectationsMock {
+        fn verify_unwrap(&self) {
+            self.was_verified.set(true);
+        }
+    }
+
+    #[test]
+    fn registered_expectations_are_available() {
+        let _guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+
+        assert!(with_static_expectations("test", |_: &ExpectationsMock| {
+            true
+        }));
+    }
+
+    #[test]
+    fn expectations_are_verified_and_unregistered_when_guard_is_dropped() {
+        let was_verified = Rc::new(Cell::new(false));
+        let guard = StaticExpectationsGuard::internal_register(ExpectationsMock {
+            was_verified: was_verified.clone(),
+        });
+
+        drop(guard);
+
+        assert!(was_verified.get());
+        let _guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "The call to test was not expected")]
+    fn panics_if_no_expectations_are_registered() {
+        with_static_expectations("test", |_: &ExpectationsMock| ());
+    }
+
+    #[test]
+    #[should_panic(expected = "are already registered on this thread")]
+    fn panics_if_expectations_are_registered_twice() {
+        let _first_guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+        let _second_guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+    }
+
+    #[test]
+    fn expectations_are_registered_per_thread() {
+        let _guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+
+        std::thread::spawn(|| {
+            let _guard = StaticExpectationsGuard::internal_register(ExpectationsMock::default());
+        })
+        .join()
+        .unwrap();
+    }
+}
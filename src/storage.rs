@@ -0,0 +1,115 @@
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// Determines how a mocked method stores its state.
+///
+/// Regular mocks use [`SingleThreadedStorage`], while mocks generated with
+/// `#[mockable(sync)]` use [`ThreadSafeStorage`], so that they are [`Send`] and [`Sync`].
+///
+/// The pointer and cell types are generic associated types, which require Rust 1.65.
+pub trait Storage {
+    /// A pointer to a value that is shared between clones of a method.
+    type Shared<T: ?Sized>: Clone + Deref<Target = T>;
+
+    /// A mutable value that is owned by a method.
+    type Cell<T>: StorageCell<T>;
+
+    /// Moves the value into a new shared pointer.
+    fn share<T>(value: T) -> Self::Shared<T>;
+}
+
+/// A value that can be mutated through a shared reference.
+pub trait StorageCell<T> {
+    /// Creates a new cell containing the given value.
+    fn new(value: T) -> Self;
+
+    /// Calls `f` with a mutable reference to the contained value.
+    fn with<U>(&self, f: impl FnOnce(&mut T) -> U) -> U;
+}
+
+/// Selects the [`Storage`] of a method through its type-erased return value generator.
+pub trait UsesStorage {
+    /// The storage used by the method.
+    type Storage: Storage;
+}
+
+/// The shared pointer used by methods storing a `E`.
+pub(crate) type Shared<E, T> = <<E as UsesStorage>::Storage as Storage>::Shared<T>;
+
+/// The cell used by methods storing a `E`.
+pub(crate) type Cell<E, T> = <<E as UsesStorage>::Storage as Storage>::Cell<T>;
+
+/// Moves the value into a new shared pointer of the storage used by methods storing a `E`.
+pub(crate) fn share<E, T>(value: T) -> Shared<E, T>
+where
+    E: UsesStorage + ?Sized,
+{
+    <E::Storage as Storage>::share(value)
+}
+
+/// Stores the state of a method that is only used on one thread.
+#[derive(Debug)]
+pub struct SingleThreadedStorage;
+
+impl Storage for SingleThreadedStorage {
+    type Shared<T: ?Sized> = Rc<T>;
+    type Cell<T> = RefCell<T>;
+
+    fn share<T>(value: T) -> Self::Shared<T> {
+        Rc::new(value)
+    }
+}
+
+impl<T> StorageCell<T> for RefCell<T> {
+    fn new(value: T) -> Self {
+        RefCell::new(value)
+    }
+
+    fn with<U>(&self, f: impl FnOnce(&mut T) -> U) -> U {
+        f(&mut self.borrow_mut())
+    }
+}
+
+/// Stores the state of a method that can be shared between threads.
+#[derive(Debug)]
+pub struct ThreadSafeStorage;
+
+impl Storage for ThreadSafeStorage {
+    type Shared<T: ?Sized> = Arc<T>;
+    type Cell<T> = Mutex<T>;
+
+    fn share<T>(value: T) -> Self::Shared<T> {
+        Arc::new(value)
+    }
+}
+
+impl<T> StorageCell<T> for Mutex<T> {
+    fn new(value: T) -> Self {
+        Mutex::new(value)
+    }
+
+    /// The contained values are never left inconsistent while the lock is held,
+    /// so they can still be used after a panic in another thread.
+    fn with<U>(&self, f: impl FnOnce(&mut T) -> U) -> U {
+        f(&mut self.lock().unwrap_or_else(|error| error.into_inner()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mutex_can_be_used_after_panic() {
+        let cell = Arc::new(<Mutex<_> as StorageCell<_>>::new(1));
+
+        {
+            let cell = cell.clone();
+            let _ = std::thread::spawn(move || cell.with(|_| panic!())).join();
+        }
+
+        assert_eq!(2, cell.with(|value| *value + 1));
+    }
+}
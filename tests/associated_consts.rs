@@ -0,0 +1,72 @@
+use mockiato::mockable;
+
+#[cfg_attr(
+    test,
+    mockable(associated_const(NAME = "greeter", VERSION = 3, ENABLED = true))
+)]
+trait Plugin {
+    const NAME: &'static str;
+    const VERSION: u32;
+    const ENABLED: bool;
+    const PRIORITY: i8 = 0;
+
+    fn run(&self, input: &str) -> String;
+}
+
+fn describe<P: Plugin>() -> String {
+    let state = if P::ENABLED { "enabled" } else { "disabled" };
+    format!(
+        "{} v{} ({}, priority {})",
+        P::NAME,
+        P::VERSION,
+        state,
+        P::PRIORITY
+    )
+}
+
+#[test]
+fn associated_consts_are_implemented() {
+    assert_eq!("greeter v3 (enabled, priority 0)", describe::<PluginMock>());
+}
+
+#[test]
+fn associated_consts_are_exposed_by_the_mock() {
+    assert_eq!("greeter", PluginMock::NAME);
+    assert_eq!(3, PluginMock::VERSION);
+}
+
+#[test]
+fn methods_can_be_mocked_alongside_associated_consts() {
+    let mut plugin = PluginMock::new();
+
+    plugin
+        .expect_run(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("Hello Jane", plugin.run("Jane"));
+}
+
+#[cfg_attr(test, mockable(associated_const(PRIORITY = 10)))]
+trait Prioritized {
+    const PRIORITY: i8 = 0;
+}
+
+#[test]
+fn default_values_of_associated_consts_can_be_overridden() {
+    assert_eq!(10, <PrioritizedMock as Prioritized>::PRIORITY);
+}
+
+#[cfg_attr(
+    test,
+    mockable(associated_type(Id = "u64"), associated_const(DEFAULT_ID = 42))
+)]
+trait Entity {
+    type Id;
+    const DEFAULT_ID: Self::Id;
+}
+
+#[test]
+fn associated_consts_can_have_associated_types() {
+    assert_eq!(42_u64, EntityMock::DEFAULT_ID);
+    assert_eq!(42_u64, <EntityMock as Entity>::DEFAULT_ID);
+}
@@ -0,0 +1,101 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[cfg_attr(test, mockable(associated_type(Item = "u32")))]
+trait Source {
+    type Item;
+
+    fn next_item(&mut self) -> Option<Self::Item>;
+}
+
+#[test]
+fn associated_type_can_be_returned() {
+    let mut source = SourceMock::new();
+
+    source
+        .expect_next_item()
+        .times(3)
+        .returns_in_sequence(vec![Some(1), Some(2), None]);
+
+    assert_eq!(Some(1), source.next_item());
+    assert_eq!(Some(2), source.next_item());
+    assert_eq!(None, source.next_item());
+}
+
+#[cfg_attr(
+    test,
+    mockable(associated_type(Item = "String", Error = "std::io::Error"))
+)]
+trait Codec {
+    type Item: Debug;
+    type Error;
+
+    fn encode(&self, item: &Self::Item) -> Result<Vec<u8>, Self::Error>;
+
+    fn decode(&self, bytes: &[u8]) -> Result<<Self as Codec>::Item, <Self as Codec>::Error>;
+}
+
+#[test]
+fn associated_types_can_be_used_as_arguments() {
+    let foo = String::from("foo");
+    let mut codec = CodecMock::new();
+
+    codec
+        .expect_encode(|arg| arg.partial_eq(&foo))
+        .returns_with(|args| Ok(args.item.as_bytes().to_vec()));
+
+    assert_eq!(b"foo".to_vec(), codec.encode(&foo).unwrap());
+}
+
+#[test]
+fn fully_qualified_associated_types_work() {
+    let mut codec = CodecMock::new();
+
+    codec
+        .expect_decode(|arg| arg.partial_eq(&b"foo"[..]))
+        .returns_with(|args| Ok(String::from_utf8(args.bytes.to_vec()).unwrap()));
+
+    assert_eq!("foo", codec.decode(b"foo").unwrap());
+}
+
+fn encode_all<C>(codec: &C, items: &[C::Item]) -> Vec<u8>
+where
+    C: Codec,
+{
+    items
+        .iter()
+        .flat_map(|item| codec.encode(item).ok().unwrap())
+        .collect()
+}
+
+#[test]
+fn mock_can_be_used_through_generic_bound() {
+    let mut codec = CodecMock::new();
+
+    codec
+        .expect_encode(|arg| arg.any())
+        .times(2)
+        .returns_with(|args| Ok(args.item.as_bytes().to_vec()));
+
+    let items = vec![String::from("ab"), String::from("c")];
+
+    assert_eq!(b"abc".to_vec(), encode_all(&codec, &items));
+}
+
+#[cfg_attr(test, mockable(associated_type(Output = "Vec<T>")))]
+trait Collector<T> {
+    type Output;
+
+    fn collect_items(&self, item: T) -> Self::Output;
+}
+
+#[test]
+fn associated_types_can_use_generic_types_of_the_trait() {
+    let mut collector = CollectorMock::<u8>::new();
+
+    collector
+        .expect_collect_items(|arg| arg.partial_eq(1))
+        .returns(vec![1, 1]);
+
+    assert_eq!(vec![1, 1], collector.collect_items(1));
+}
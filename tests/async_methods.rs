@@ -0,0 +1,137 @@
+use mockiato::mockable;
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+#[cfg_attr(test, mockable)]
+trait UserService {
+    async fn user_name(&self, id: u64) -> String;
+
+    async fn delete_user(&self, id: u64);
+}
+
+#[test]
+fn async_fn_returns_expected_value() {
+    let mut user_service = UserServiceMock::new();
+
+    user_service
+        .expect_user_name(|arg| arg.partial_eq(42))
+        .returns(String::from("Jane"));
+
+    assert_eq!("Jane", block_on(user_service.user_name(42)));
+}
+
+#[test]
+fn async_fn_without_return_value_works() {
+    let mut user_service = UserServiceMock::new();
+
+    user_service
+        .expect_delete_user(|arg| arg.partial_eq(42))
+        .returns(());
+
+    block_on(user_service.delete_user(42));
+}
+
+#[test]
+#[should_panic(expected = "was not expected.")]
+fn async_fn_panics_when_awaited_with_unexpected_arguments() {
+    let mut user_service = UserServiceMock::new();
+
+    user_service
+        .expect_user_name(|arg| arg.partial_eq(42))
+        .times(..)
+        .returns(String::from("Jane"));
+
+    block_on(user_service.user_name(7));
+}
+
+/// Mirrors the signatures that `#[async_trait]` generates for `async fn`s.
+#[cfg_attr(test, mockable(sync, async_trait))]
+trait UserRepository {
+    fn find_user_name<'life0, 'async_trait>(
+        &'life0 self,
+        id: u64,
+    ) -> Pin<Box<dyn Future<Output = Option<String>> + Send + 'async_trait>>
+    where
+        'life0: 'async_trait,
+        Self: Sync + 'async_trait;
+}
+
+#[test]
+fn boxed_future_returns_expected_value() {
+    let mut user_repository = UserRepositoryMock::new();
+
+    user_repository
+        .expect_find_user_name(|arg| arg.partial_eq(42))
+        .returns(Some(String::from("Jane")));
+    user_repository
+        .expect_find_user_name(|arg| arg.partial_eq(7))
+        .returns(None);
+
+    assert_eq!(
+        Some(String::from("Jane")),
+        block_on(user_repository.find_user_name(42))
+    );
+    assert_eq!(None, block_on(user_repository.find_user_name(7)));
+}
+
+#[test]
+fn boxed_future_can_be_used_through_trait_object() {
+    let mut user_repository = UserRepositoryMock::new();
+
+    user_repository
+        .expect_find_user_name(|arg| arg.any())
+        .returns_with(|args| Some(format!("User {}", args.id)));
+
+    let user_repository: Box<dyn UserRepository + Send + Sync> = Box::new(user_repository);
+
+    assert_eq!(
+        Some(String::from("User 3")),
+        block_on(user_repository.find_user_name(3))
+    );
+}
+
+#[cfg_attr(test, mockable)]
+trait Scheduler {
+    fn schedule(&self, id: u64) -> Pin<Box<dyn Future<Output = u64>>>;
+}
+
+#[test]
+fn boxed_future_is_returned_as_is_without_async_trait() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(|arg| arg.partial_eq(42))
+        .returns_with(|args| {
+            let id = args.id;
+            Box::pin(async move { id + 1 })
+        });
+
+    assert_eq!(43, block_on(scheduler.schedule(42)));
+}
+
+/// Polls the future to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(ptr::null(), &VTABLE)
+    }
+
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+}
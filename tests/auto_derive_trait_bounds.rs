@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use mockiato::mockable;
 use std::fmt;
 
@@ -0,0 +1,231 @@
+use mockiato::mockable;
+use std::rc::Rc;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str, times: u32) -> String;
+
+    fn leave(&self);
+}
+
+#[mockable]
+trait Handler {
+    fn handle<T>(&self, id: u32, value: T)
+    where
+        T: 'static;
+}
+
+#[derive(Debug, PartialEq)]
+struct Token(u32);
+
+#[mockable]
+trait Consumer {
+    fn consume(&self, token: Token, payload: &[u8], shared: Rc<u32>) -> bool;
+}
+
+#[mockable(sync)]
+trait SharedConsumer {
+    fn consume(&self, shared: Rc<u32>, id: u32);
+}
+
+#[mockable]
+trait Store<V> {
+    fn insert(&self, key: &str, value: V);
+}
+
+struct RealGreeter;
+
+impl Greeter for RealGreeter {
+    fn greet(&self, name: &str, _times: u32) -> String {
+        format!("Hello {}", name)
+    }
+
+    fn leave(&self) {}
+}
+
+fn greeter() -> GreeterMock<'static> {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any(), |arg| arg.any())
+        .times(..)
+        .returns(String::from("Hello"));
+    greeter.expect_leave().times(..).returns(());
+
+    greeter
+}
+
+#[test]
+fn history_is_empty_if_method_was_not_called() {
+    let greeter = greeter();
+
+    let calls = greeter.calls_to_greet();
+
+    assert!(calls.is_empty());
+    calls.assert_not_called();
+}
+
+#[test]
+fn every_call_is_recorded() {
+    let greeter = greeter();
+
+    greeter.greet("Jane", 1);
+    greeter.greet("John", 2);
+    greeter.leave();
+
+    greeter.calls_to_greet().assert_called_times(2);
+    greeter.calls_to_leave().assert_called();
+}
+
+#[test]
+#[should_panic(expected = "GreeterMock::leave was expected to be called")]
+fn assert_called_panics_if_method_was_not_called() {
+    let greeter = greeter();
+
+    greeter.greet("Jane", 1);
+
+    greeter.calls_to_leave().assert_called();
+}
+
+#[test]
+fn arguments_are_recorded_in_order() {
+    let greeter = greeter();
+
+    greeter.greet("Jane", 1);
+    greeter.greet("John", 2);
+
+    let calls = greeter.calls_to_greet();
+    let names: Vec<_> = calls
+        .iter()
+        .map(|call| call.arguments().name.get())
+        .collect();
+
+    assert_eq!(vec![Some("Jane"), Some("John")], names);
+    calls.assert_called_with(|args| &*args.name == "Jane" && *args.times == 1);
+    calls.assert_last_called_with(|args| &*args.name == "John" && *args.times == 2);
+}
+
+#[test]
+#[should_panic(expected = "GreeterMock::greet was expected to be called with matching arguments")]
+fn assert_called_with_panics_if_no_call_matches() {
+    let greeter = greeter();
+
+    greeter.greet("Jane", 1);
+
+    greeter
+        .calls_to_greet()
+        .assert_called_with(|args| &*args.name == "Paul");
+}
+
+#[test]
+fn calls_answered_by_the_fallback_are_recorded() {
+    let mut greeter = GreeterMock::new();
+    greeter.allow_unexpected_calls();
+    greeter.fallback_greet().returns(String::from("Fallback"));
+
+    greeter.greet("Jane", 1);
+
+    greeter
+        .calls_to_greet()
+        .assert_last_called_with(|args| &*args.name == "Jane");
+}
+
+#[test]
+fn calls_forwarded_to_the_real_implementation_are_recorded() {
+    let greeter = GreeterMock::with_fallback(RealGreeter);
+
+    assert_eq!("Hello Jane", greeter.greet("Jane", 1));
+
+    greeter
+        .calls_to_greet()
+        .assert_last_called_with(|args| &*args.name == "Jane");
+}
+
+#[test]
+fn rejected_calls_are_not_recorded() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"), |arg| arg.any())
+        .returns(String::from("Hello"));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        greeter.greet("John", 1);
+    }));
+    assert!(result.is_err());
+    greeter.greet("Jane", 1);
+
+    let calls = greeter.calls_to_greet();
+    calls.assert_called_times(1);
+    calls.assert_called_with(|args| &*args.name == "Jane");
+}
+
+#[test]
+fn arguments_that_can_not_be_cloned_are_not_recorded() {
+    let mut consumer = ConsumerMock::new();
+    consumer
+        .expect_consume(|arg| arg.any(), |arg| arg.any(), |arg| arg.any())
+        .returns(true);
+
+    consumer.consume(Token(1), &[1, 2, 3], Rc::new(42));
+
+    let calls = consumer.calls_to_consume();
+    let arguments = calls.last().unwrap().arguments();
+
+    assert!(!arguments.token.is_recorded());
+    assert_eq!(Some(&[1, 2, 3][..]), arguments.payload.get());
+    assert_eq!(42, **arguments.shared);
+}
+
+#[test]
+fn arguments_that_are_not_thread_safe_are_not_recorded_by_thread_safe_mocks() {
+    let mut consumer = SharedConsumerMock::new();
+    consumer
+        .expect_consume(|arg| arg.any(), |arg| arg.any())
+        .returns(());
+
+    consumer.consume(Rc::new(42), 7);
+
+    let calls = consumer.calls_to_consume();
+    let arguments = calls.last().unwrap().arguments();
+
+    assert!(!arguments.shared.is_recorded());
+    assert_eq!(7, *arguments.id);
+}
+
+#[test]
+fn arguments_using_type_parameters_are_not_recorded() {
+    let mut store = StoreMock::new();
+    store
+        .expect_insert(|arg| arg.any(), |arg| arg.any())
+        .returns(());
+
+    store.insert("answer", 42_u32);
+
+    store
+        .calls_to_insert()
+        .assert_called_with(|args| &*args.key == "answer");
+}
+
+#[test]
+fn calls_to_generic_methods_are_recorded_per_type_parameter() {
+    let mut handler = HandlerMock::new();
+    handler
+        .expect_handle::<u32>(|arg| arg.any(), |arg| arg.any())
+        .returns(());
+    handler
+        .expect_handle::<String>(|arg| arg.any(), |arg| arg.any())
+        .times(2)
+        .returns(());
+
+    handler.handle(1, 42_u32);
+    handler.handle(2, String::from("foo"));
+    handler.handle(3, String::from("bar"));
+
+    handler
+        .calls_to_handle::<u32>()
+        .assert_called_with(|args| *args.id == 1);
+
+    let calls = handler.calls_to_handle::<String>();
+    calls.assert_called_times(2);
+    calls.assert_last_called_with(|args| *args.id == 3);
+}
@@ -0,0 +1,144 @@
+use mockiato::{mockable, Captured};
+use std::panic::{self, AssertUnwindSafe};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Callback {
+    id: u32,
+}
+
+#[mockable]
+trait EventBus {
+    fn subscribe(&self, topic: &str, callback: &Callback) -> bool;
+
+    fn publish(&self, payload: Vec<u8>);
+}
+
+#[mockable(sync)]
+trait TokenStore {
+    fn store(&self, token: String);
+}
+
+#[test]
+fn captured_reference_arguments_are_cloned() {
+    let topic: Captured<String> = Captured::new();
+    let callback = Captured::new();
+    let mut event_bus = EventBusMock::new();
+    event_bus
+        .expect_subscribe(|arg| arg.capture(&topic), |arg| arg.capture(&callback))
+        .returns(true);
+
+    event_bus.subscribe("news", &Callback { id: 7 });
+
+    assert_eq!("news", topic.single());
+    assert_eq!(Callback { id: 7 }, callback.single());
+}
+
+#[test]
+fn every_matched_argument_is_captured() {
+    let payloads = Captured::new();
+    let mut event_bus = EventBusMock::new();
+    event_bus
+        .expect_publish(|arg| arg.capture(&payloads))
+        .times(2)
+        .returns(());
+
+    event_bus.publish(vec![1]);
+    event_bus.publish(vec![2, 3]);
+
+    assert_eq!(vec![vec![1], vec![2, 3]], payloads.all());
+    assert_eq!(Some(vec![2, 3]), payloads.last());
+}
+
+#[test]
+fn only_arguments_matched_by_inner_matcher_are_captured() {
+    let callback = Captured::new();
+    let mut event_bus = EventBusMock::new();
+    event_bus
+        .expect_subscribe(
+            |arg| arg.partial_eq("news"),
+            |arg| {
+                arg.capture_matching(
+                    &callback,
+                    arg.field(
+                        |callback: &Callback| &callback.id,
+                        |arg| arg.greater_than(5),
+                    ),
+                )
+            },
+        )
+        .returns(true);
+    event_bus
+        .expect_subscribe(
+            |arg| arg.any(),
+            |arg| arg.field(|callback: &Callback| &callback.id, |arg| arg.less_than(5)),
+        )
+        .returns(false);
+
+    assert!(!event_bus.subscribe("news", &Callback { id: 1 }));
+    assert!(event_bus.subscribe("news", &Callback { id: 10 }));
+
+    assert_eq!(Callback { id: 10 }, callback.single());
+}
+
+#[test]
+fn arguments_of_rejected_calls_are_not_captured() {
+    let topic: Captured<String> = Captured::new();
+    let mut event_bus = EventBusMock::new();
+    event_bus
+        .expect_subscribe(
+            |arg| arg.capture(&topic),
+            |arg| arg.partial_eq_owned(Callback { id: 1 }),
+        )
+        .times(1)
+        .returns(true);
+
+    let rejected = panic::catch_unwind(AssertUnwindSafe(|| {
+        event_bus.subscribe("rejected", &Callback { id: 2 })
+    }));
+    assert!(rejected.is_err());
+    assert!(topic.all().is_empty());
+
+    assert!(event_bus.subscribe("accepted", &Callback { id: 1 }));
+
+    let over_limit = panic::catch_unwind(AssertUnwindSafe(|| {
+        event_bus.subscribe("over-limit", &Callback { id: 1 })
+    }));
+    assert!(over_limit.is_err());
+    assert_eq!(vec![String::from("accepted")], topic.all());
+}
+
+#[test]
+fn nested_captures_only_capture_accepted_arguments() {
+    let small = Captured::new();
+    let large = Captured::new();
+    let mut event_bus = EventBusMock::new();
+    event_bus
+        .expect_publish(|arg| {
+            arg.any_of((
+                arg.capture_matching(&small, arg.has_len(1)),
+                arg.capture_matching(&large, arg.has_len(2)),
+            ))
+        })
+        .times(2)
+        .returns(());
+
+    event_bus.publish(vec![1]);
+    event_bus.publish(vec![2, 3]);
+
+    assert_eq!(vec![1], small.single());
+    assert_eq!(vec![2, 3], large.single());
+}
+
+#[test]
+fn thread_safe_mocks_can_capture_arguments() {
+    let token = Captured::new();
+    let mut store = TokenStoreMock::new();
+    store.expect_store(|arg| arg.capture(&token)).returns(());
+
+    let captured_token = token.clone();
+    std::thread::spawn(move || store.store(String::from("secret")))
+        .join()
+        .unwrap();
+
+    assert_eq!(Some(String::from("secret")), captured_token.last());
+}
@@ -0,0 +1,110 @@
+use mockiato::mockable;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn leave(&self);
+}
+
+#[mockable]
+trait Handler {
+    fn handle<T>(&self, value: T)
+    where
+        T: 'static;
+}
+
+#[test]
+fn try_verify_is_ok_if_expected_calls_were_made() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Jane");
+
+    assert!(greeter.try_verify().is_ok());
+}
+
+#[test]
+fn try_verify_returns_an_error_for_each_unsatisfied_method() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    greeter.expect_leave().returns(());
+
+    let error = greeter.try_verify().unwrap_err();
+
+    let method_names: Vec<_> = error
+        .verification_errors()
+        .iter()
+        .map(|error| error.method_name())
+        .collect();
+    assert_eq!(
+        vec!["GreeterMock::greet", "GreeterMock::leave"],
+        method_names
+    );
+
+    greeter.greet("Jane");
+    greeter.leave();
+}
+
+#[test]
+fn new_calls_can_be_expected_after_checkpoint() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+
+    greeter.checkpoint();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("John"))
+        .returns(String::from("Hello John"));
+
+    assert_eq!("Hello John", greeter.greet("John"));
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::leave were not satisified.")]
+fn checkpoint_panics_if_expected_calls_were_not_made() {
+    let mut greeter = GreeterMock::new();
+    greeter.expect_leave().returns(());
+
+    greeter.checkpoint();
+}
+
+#[test]
+#[should_panic(expected = "The call GreeterMock::greet")]
+fn checkpoint_panics_if_unexpected_calls_were_made() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(..)
+        .returns(String::from("Hello Jane"));
+
+    let result = catch_unwind(AssertUnwindSafe(|| greeter.greet("John")));
+    assert!(result.is_err());
+
+    greeter.checkpoint();
+}
+
+#[test]
+fn checkpoint_clears_expected_calls_of_generic_methods() {
+    let mut handler = HandlerMock::new();
+    handler.expect_handle::<u32>(|arg| arg.any()).returns(());
+
+    handler.handle(42_u32);
+    handler.checkpoint();
+
+    handler
+        .expect_handle::<u32>(|arg| arg.partial_eq(7))
+        .returns(());
+    assert!(handler.try_verify().is_err());
+
+    handler.handle(7_u32);
+}
@@ -0,0 +1,87 @@
+use mockiato::mockable;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+#[mockable]
+trait Mailer {
+    fn send(&self, recipients: &[&str]);
+
+    fn send_all(&self, recipients: Vec<String>);
+
+    fn enqueue(&self, queue: &VecDeque<u32>);
+
+    fn subscribe(&self, topics: &HashSet<String>);
+
+    fn tag(&self, tags: BTreeSet<u32>);
+
+    fn configure(&self, settings: &HashMap<String, String>);
+}
+
+#[test]
+fn contains_element_and_has_len_match_slices() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_send(|arg| arg.all_of((arg.contains_element("Jane"), arg.has_len(2))))
+        .returns(());
+
+    mailer.send(&["John", "Jane"]);
+}
+
+#[test]
+fn each_matches_every_element_of_vec() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_send_all(|arg| arg.each(arg.ends_with("@example.com")))
+        .returns(());
+
+    mailer.send_all(vec![
+        String::from("jane@example.com"),
+        String::from("john@example.com"),
+    ]);
+}
+
+#[test]
+fn elements_in_order_matches_vec_deque() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_enqueue(|arg| arg.elements_in_order([arg.partial_eq(1), arg.partial_eq(2)]))
+        .returns(());
+
+    mailer.enqueue(&vec![1, 2].into_iter().collect());
+}
+
+#[test]
+fn collection_matchers_work_with_sets() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_subscribe(|arg| arg.contains_element(String::from("news")))
+        .returns(());
+    mailer.expect_tag(|arg| arg.is_empty()).returns(());
+
+    mailer.subscribe(&vec![String::from("news")].into_iter().collect());
+    mailer.tag(BTreeSet::new());
+}
+
+#[test]
+fn collection_matchers_match_keys_of_hash_map() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_configure(|arg| arg.unordered_vec_eq(vec!["host", "port"]))
+        .returns(());
+
+    let settings = vec![
+        (String::from("port"), String::from("25")),
+        (String::from("host"), String::from("localhost")),
+    ];
+    mailer.configure(&settings.into_iter().collect());
+}
+
+#[test]
+#[should_panic(expected = "send(each(starts_with(\"J\")))")]
+fn collection_matchers_are_displayed_in_failed_calls() {
+    let mut mailer = MailerMock::new();
+    mailer
+        .expect_send(|arg| arg.each(arg.starts_with("J")))
+        .returns(());
+
+    mailer.send(&["Jane", "Peter"]);
+}
@@ -0,0 +1,90 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn greet_age(&self, age: u32) -> String;
+}
+
+#[test]
+fn not_matches_any_value_except_the_given_one() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.not(arg.partial_eq("Jane")))
+        .returns(String::from("Hello stranger"));
+
+    assert_eq!("Hello stranger", greeter.greet("John"));
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn not_does_not_match_the_given_value() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.not(arg.partial_eq("Jane")))
+        .returns(String::from("Hello stranger"));
+
+    greeter.greet("Jane");
+}
+
+#[test]
+fn any_of_matches_one_of_the_given_values() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.any_of((arg.partial_eq("Jane"), arg.partial_eq("John"))))
+        .times(2)
+        .returns(String::from("Hello friend"));
+
+    assert_eq!("Hello friend", greeter.greet("Jane"));
+    assert_eq!("Hello friend", greeter.greet("John"));
+}
+
+#[test]
+fn all_of_matches_if_all_matchers_match() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet_age(|arg| {
+            arg.all_of((
+                arg.matches(|age: &u32| *age >= 18),
+                arg.matches(|age: &u32| *age < 65),
+            ))
+        })
+        .returns(String::from("Hello adult"));
+
+    assert_eq!("Hello adult", greeter.greet_age(30));
+}
+
+#[test]
+fn any_of_accepts_a_list_of_matchers() {
+    let names = ["Jane", "John"];
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| {
+            arg.any_of(
+                names
+                    .iter()
+                    .map(|name| arg.partial_eq(*name))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .returns(String::from("Hello friend"));
+
+    assert_eq!("Hello friend", greeter.greet("John"));
+}
+
+#[test]
+#[should_panic(expected = "greet_age((>= 18 & not(< 65)))")]
+fn combinators_are_displayed_in_failed_calls() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet_age(|arg| {
+            arg.all_of((
+                arg.matches(|age: &u32| *age >= 18).described_as(">= 18"),
+                arg.not(arg.matches(|age: &u32| *age < 65).described_as("< 65")),
+            ))
+        })
+        .returns(String::from("Hello senior"));
+
+    greeter.greet_age(30);
+}
@@ -0,0 +1,73 @@
+use mockiato::{mockable, ArgumentMatcher};
+use std::fmt::{self, Display};
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn greet_many(&self, count: u32) -> String;
+}
+
+#[derive(Debug)]
+struct ShortNameArgumentMatcher;
+
+impl Display for ShortNameArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<short name>")
+    }
+}
+
+impl ArgumentMatcher<&str> for ShortNameArgumentMatcher {
+    fn matches_argument(&self, input: &&str) -> bool {
+        input.len() <= 4
+    }
+}
+
+#[test]
+fn custom_matchers_can_be_used() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|_| ShortNameArgumentMatcher)
+        .returns(String::from("Hi"));
+
+    assert_eq!("Hi", greeter.greet("Jane"));
+}
+
+#[test]
+#[should_panic(expected = "greet(<short name>)")]
+fn custom_matchers_are_displayed_in_failed_calls() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|_| ShortNameArgumentMatcher)
+        .returns(String::from("Hi"));
+
+    greeter.greet("Josephine");
+}
+
+#[test]
+fn predicates_can_be_used_as_matchers() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.matches(|name: &&str| name.starts_with('J')))
+        .returns(String::from("Hi J"));
+    greeter
+        .expect_greet_many(|arg| arg.matches(|count: &u32| *count > 1))
+        .returns(String::from("Hi everyone"));
+
+    assert_eq!("Hi J", greeter.greet("Jane"));
+    assert_eq!("Hi everyone", greeter.greet_many(3));
+}
+
+#[test]
+#[should_panic(expected = "greet_many(more than one)")]
+fn predicate_description_is_displayed_in_failed_calls() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet_many(|arg| {
+            arg.matches(|count: &u32| *count > 1)
+                .described_as("more than one")
+        })
+        .returns(String::from("Hi everyone"));
+
+    greeter.greet_many(1);
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::blacklisted_name)]
+#![allow(dead_code, clippy::disallowed_names)]
 
 use mockiato::mockable;
 
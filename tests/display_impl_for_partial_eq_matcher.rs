@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use mockiato::mockable;
 #[cfg(rustc_is_nightly)]
 use mockiato::Argument;
@@ -0,0 +1,103 @@
+use mockiato::mockable;
+
+#[derive(Debug)]
+struct Request {
+    user: User,
+    path: String,
+}
+
+#[derive(Debug)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[mockable]
+trait Client {
+    fn send(&self, request: &Request) -> u16;
+
+    fn send_owned(&self, request: Request) -> u16;
+}
+
+fn request(id: u32, path: &str) -> Request {
+    Request {
+        user: User {
+            id,
+            name: String::from("Jane"),
+        },
+        path: path.to_string(),
+    }
+}
+
+#[test]
+fn field_matcher_matches_part_of_reference_argument() {
+    let mut client = ClientMock::new();
+    client
+        .expect_send(|arg| {
+            arg.field(
+                |request: &Request| &request.path,
+                |arg| arg.starts_with("/users"),
+            )
+        })
+        .returns(200);
+
+    assert_eq!(200, client.send(&request(1, "/users/1")));
+}
+
+#[test]
+fn field_matchers_can_be_nested() {
+    let mut client = ClientMock::new();
+    client
+        .expect_send_owned(|arg| {
+            arg.field(
+                |request: &Request| &request.user,
+                |arg| {
+                    arg.all_of((
+                        arg.field(|user: &User| &user.id, |arg| arg.partial_eq(42)),
+                        arg.field(|user: &User| &user.name, |arg| arg.partial_eq("Jane")),
+                    ))
+                },
+            )
+        })
+        .returns(200);
+
+    assert_eq!(200, client.send_owned(request(42, "/")));
+}
+
+#[test]
+#[should_panic(expected = "send(path: starts_with(\"/users\"))")]
+fn label_is_displayed_in_failed_calls() {
+    let mut client = ClientMock::new();
+    client
+        .expect_send(|arg| {
+            arg.field(
+                |request: &Request| &request.path,
+                |arg| arg.starts_with("/users"),
+            )
+            .labelled("path")
+        })
+        .returns(200);
+
+    client.send(&request(1, "/admin"));
+}
+
+#[cfg(rustc_is_nightly)]
+#[test]
+#[should_panic(expected = "send(user: id: 42)")]
+fn nested_labels_are_displayed_in_failed_calls() {
+    let mut client = ClientMock::new();
+    client
+        .expect_send(|arg| {
+            arg.field(
+                |request: &Request| &request.user,
+                |arg| {
+                    arg.field(|user: &User| &user.id, |arg| arg.partial_eq(42))
+                        .labelled("id")
+                },
+            )
+            .labelled("user")
+        })
+        .returns(200);
+
+    client.send(&request(1, "/"));
+}
@@ -0,0 +1,167 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::thread;
+
+#[cfg_attr(test, mockable)]
+trait Handler {
+    fn handle<T: Debug + 'static>(&self, value: T) -> bool;
+
+    fn handle_ref<T>(&self, value: &T) -> String
+    where
+        T: Debug + 'static;
+
+    fn create<T: Default + 'static>(&self, name: &str) -> T;
+
+    fn convert<S: 'static, T: 'static>(&self, source: S) -> T;
+}
+
+#[test]
+fn expectations_are_keyed_on_type_parameter() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle::<u32>(|arg| arg.partial_eq(42))
+        .returns(true);
+    handler
+        .expect_handle::<String>(|arg| arg.partial_eq(String::from("foo")))
+        .returns(false);
+
+    assert!(handler.handle(42_u32));
+    assert!(!handler.handle(String::from("foo")));
+}
+
+#[test]
+fn type_parameter_can_be_inferred_from_matcher() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle(|arg| arg.partial_eq(42_u8))
+        .returns(true);
+
+    assert!(handler.handle(42_u8));
+}
+
+#[test]
+fn references_to_type_parameters_work() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle_ref::<u64>(|arg| arg.partial_eq(&7))
+        .returns(String::from("seven"));
+
+    assert_eq!("seven", handler.handle_ref(&7_u64));
+}
+
+#[test]
+fn type_parameter_only_used_in_return_type_works() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_create::<u32>(|arg| arg.partial_eq("answer"))
+        .returns(42);
+    handler
+        .expect_create::<String>(|arg| arg.any())
+        .returns_with(|args| args.name.to_uppercase());
+
+    assert_eq!(42_u32, handler.create("answer"));
+    assert_eq!("QUESTION", handler.create::<String>("question"));
+}
+
+#[test]
+fn multiple_type_parameters_work() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_convert::<u8, u64>(|arg| arg.partial_eq(3))
+        .returns(3);
+
+    assert_eq!(3_u64, handler.convert(3_u8));
+}
+
+#[test]
+#[should_panic(
+    expected = "Calls to HandlerMock::handle were only expected with these type parameters:"
+)]
+fn calls_with_unexpected_type_parameter_panic() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle::<u32>(|arg| arg.any())
+        .times(..)
+        .returns(true);
+
+    handler.handle(42_i64);
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for HandlerMock::handle::<u32> were not satisified.")]
+fn expectations_are_verified_for_each_type_parameter() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle::<u32>(|arg| arg.any())
+        .times(1)
+        .returns(true);
+    handler
+        .expect_handle::<String>(|arg| arg.any())
+        .times(1)
+        .returns(true);
+
+    handler.handle(String::from("foo"));
+}
+
+#[test]
+fn ordered_expectations_work_with_type_parameters() {
+    let mut handler = HandlerMock::new();
+
+    handler.expect_handle_calls_in_order();
+    handler
+        .expect_handle::<u32>(|arg| arg.partial_eq(1))
+        .returns(true);
+    handler
+        .expect_handle::<u32>(|arg| arg.partial_eq(2))
+        .returns(false);
+
+    assert!(handler.handle(1_u32));
+    assert!(!handler.handle(2_u32));
+}
+
+#[test]
+fn mocks_with_generic_methods_can_be_cloned() {
+    let mut handler = HandlerMock::new();
+
+    handler
+        .expect_handle::<u32>(|arg| arg.any())
+        .times(2)
+        .returns(true);
+
+    let cloned_handler = handler.clone();
+
+    assert!(handler.handle(1_u32));
+    assert!(handler.handle(2_u32));
+    assert!(cloned_handler.handle(1_u32));
+    assert!(cloned_handler.handle(2_u32));
+}
+
+#[cfg_attr(test, mockable(sync))]
+trait Serializer<W> {
+    fn serialize<T: Debug + 'static>(&self, writer: W, value: &T) -> usize;
+}
+
+#[test]
+fn generic_methods_work_on_thread_safe_generic_traits() {
+    let mut serializer = SerializerMock::<Vec<u8>>::new();
+
+    serializer
+        .expect_serialize::<&'static str>(|arg| arg.any(), |arg| arg.partial_eq(&"foo"))
+        .returns(3);
+
+    let serializer = Arc::new(serializer);
+
+    let written_bytes = thread::spawn(move || serializer.serialize(Vec::new(), &"foo"))
+        .join()
+        .unwrap();
+
+    assert_eq!(3, written_bytes);
+}
@@ -0,0 +1,63 @@
+use mockiato::mockable;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[mockable]
+trait Canvas {
+    fn clear(&self, _: u32);
+
+    fn resize(&self, _: u32, height: u32, _: u32) -> u32;
+
+    fn fill(&self, _: u32, arg0: u32) -> u32;
+
+    fn draw_line(&self, (x1, y1): (i32, i32), Point { x, y }: Point) -> i32 {
+        (x - x1) + (y - y1)
+    }
+}
+
+#[test]
+fn wildcard_arguments_can_be_matched() {
+    let mut canvas = CanvasMock::new();
+    canvas
+        .expect_clear(|arg| arg.partial_eq(0xFF_FF_FF))
+        .returns(());
+
+    canvas.clear(0xFF_FF_FF);
+}
+
+#[test]
+fn wildcard_arguments_are_named_after_their_position() {
+    let mut canvas = CanvasMock::new();
+    canvas
+        .expect_resize(|arg| arg.partial_eq(800), |arg| arg.any(), |arg| arg.any())
+        .returns_with(|args| args.arg0 * args.height + args.arg2);
+
+    assert_eq!(480_002, canvas.resize(800, 600, 2));
+}
+
+#[test]
+fn synthesized_names_do_not_collide_with_named_arguments() {
+    let mut canvas = CanvasMock::new();
+    canvas
+        .expect_fill(|arg| arg.partial_eq(1), |arg| arg.partial_eq(2))
+        .returns_with(|args| args.arg0_ * 10 + args.arg0);
+
+    assert_eq!(12, canvas.fill(1, 2));
+}
+
+#[test]
+fn destructured_arguments_can_be_matched() {
+    let mut canvas = CanvasMock::new();
+    canvas
+        .expect_draw_line(
+            |arg| arg.partial_eq((0, 0)),
+            |arg| arg.partial_eq(Point { x: 3, y: 4 }),
+        )
+        .returns_with(|args| args.arg1.x + args.arg1.y - args.arg0.0);
+
+    assert_eq!(7, canvas.draw_line((0, 0), Point { x: 3, y: 4 }));
+}
@@ -0,0 +1,127 @@
+use mockiato::{mockable, CallErrorKind};
+
+#[mockable(lenient)]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn greeting_count(&self) -> u32;
+
+    fn handle<T>(&self, value: T)
+    where
+        T: 'static;
+}
+
+#[mockable]
+trait Repository {
+    fn find(&self, id: u32) -> Option<String>;
+
+    fn connection(&self) -> Connection;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Connection(u32);
+
+#[test]
+fn unexpected_calls_return_default_value() {
+    let greeter = GreeterMock::new();
+
+    assert_eq!("", greeter.greet("Jane"));
+    assert_eq!(0, greeter.greeting_count());
+}
+
+#[test]
+fn expected_calls_take_precedence_over_fallback() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("", greeter.greet("John"));
+}
+
+#[test]
+fn unexpected_calls_return_configured_fallback() {
+    let mut greeter = GreeterMock::new();
+    greeter.fallback_greeting_count().returns(42);
+    greeter
+        .fallback_greet()
+        .returns_with(|args| format!("Hi {}", args.name));
+
+    assert_eq!(42, greeter.greeting_count());
+    assert_eq!("Hi John", greeter.greet("John"));
+}
+
+#[test]
+fn unexpected_calls_are_reported() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Jane");
+    greeter.greet("John");
+    greeter.greeting_count();
+
+    let unexpected_calls = greeter.unexpected_calls();
+    let method_names: Vec<_> = unexpected_calls
+        .iter()
+        .map(|call| call.method_name())
+        .collect();
+
+    assert_eq!(
+        vec!["GreeterMock::greet", "GreeterMock::greeting_count"],
+        method_names
+    );
+    match unexpected_calls[0].kind() {
+        CallErrorKind::NoMatchingCall { expected_calls } => assert_eq!(1, expected_calls.len()),
+        kind => panic!("Unexpected call error: {:?}", kind),
+    }
+    assert!(greeter.verify().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn unexpected_calls_to_generic_methods_panic() {
+    let greeter = GreeterMock::new();
+
+    greeter.handle(42_u32);
+}
+
+#[test]
+fn unexpected_calls_can_be_allowed_at_runtime() {
+    let mut repository = RepositoryMock::new();
+    repository.allow_unexpected_calls();
+
+    assert_eq!(None, repository.find(1));
+    assert_eq!(1, repository.unexpected_calls().len());
+}
+
+#[test]
+fn fallback_is_used_for_return_type_without_default() {
+    let mut repository = RepositoryMock::new();
+    repository.fallback_connection().returns(Connection(1));
+    repository.allow_unexpected_calls();
+
+    assert_eq!(Connection(1), repository.connection());
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn unexpected_call_panics_without_default_or_fallback() {
+    let mut repository = RepositoryMock::new();
+    repository.allow_unexpected_calls();
+
+    repository.connection();
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn fallback_is_not_used_unless_unexpected_calls_are_allowed() {
+    let mut repository = RepositoryMock::new();
+    repository
+        .fallback_find()
+        .returns(Some(String::from("foo")));
+
+    repository.find(1);
+}
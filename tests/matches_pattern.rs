@@ -0,0 +1,63 @@
+use mockiato::{matches_pattern, mockable};
+
+#[derive(Debug)]
+enum Message {
+    Ping { id: u32 },
+    Text(String),
+}
+
+#[mockable]
+trait MessageSender {
+    fn send(&self, message: &Message) -> bool;
+
+    fn send_optional(&self, message: Option<Message>) -> bool;
+}
+
+#[test]
+fn pattern_matches_reference_arguments() {
+    let mut sender = MessageSenderMock::new();
+    sender
+        .expect_send(|_| matches_pattern!(Message::Ping { .. }))
+        .returns(true);
+
+    assert!(sender.send(&Message::Ping { id: 1 }));
+}
+
+#[test]
+fn pattern_matches_owned_arguments() {
+    let mut sender = MessageSenderMock::new();
+    sender
+        .expect_send_optional(|_| matches_pattern!(Some(Message::Text(text)) if text == "Hi"))
+        .returns(true);
+    sender
+        .expect_send_optional(|_| matches_pattern!(None))
+        .returns(false);
+
+    assert!(sender.send_optional(Some(Message::Text(String::from("Hi")))));
+    assert!(!sender.send_optional(None));
+}
+
+#[test]
+fn pattern_guard_is_applied() {
+    let mut sender = MessageSenderMock::new();
+    sender
+        .expect_send(|_| matches_pattern!(Message::Ping { id } if *id > 10))
+        .returns(true);
+    sender
+        .expect_send(|_| matches_pattern!(Message::Ping { id } if *id <= 10))
+        .returns(false);
+
+    assert!(sender.send(&Message::Ping { id: 42 }));
+    assert!(!sender.send(&Message::Ping { id: 1 }));
+}
+
+#[test]
+#[should_panic(expected = "send(Message::Ping { .. })")]
+fn pattern_is_displayed_in_failed_calls() {
+    let mut sender = MessageSenderMock::new();
+    sender
+        .expect_send(|_| matches_pattern!(Message::Ping { .. }))
+        .returns(true);
+
+    sender.send(&Message::Text(String::from("Hi")));
+}
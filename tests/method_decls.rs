@@ -1,3 +1,5 @@
+#![allow(dead_code, clippy::needless_lifetimes)]
+
 use mockiato::mockable;
 
 use std::borrow::Cow;
@@ -0,0 +1,56 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Scheduler {
+    fn schedule(&self, timestamp: u64) -> bool;
+
+    fn set_volume(&self, volume: f32);
+}
+
+#[test]
+fn greater_than_and_less_than_match_bounds() {
+    let mut scheduler = SchedulerMock::new();
+    scheduler
+        .expect_schedule(|arg| arg.greater_than(1000))
+        .returns(true);
+    scheduler
+        .expect_schedule(|arg| arg.less_than(1000))
+        .returns(false);
+
+    assert!(scheduler.schedule(2000));
+    assert!(!scheduler.schedule(500));
+}
+
+#[test]
+fn range_matchers_work_with_floats() {
+    let mut scheduler = SchedulerMock::new();
+    scheduler
+        .expect_set_volume(|arg| arg.between(0.0, 1.0))
+        .times(2)
+        .returns(());
+
+    scheduler.set_volume(0.0);
+    scheduler.set_volume(0.5);
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn in_range_does_not_match_values_outside_of_range() {
+    let mut scheduler = SchedulerMock::new();
+    scheduler
+        .expect_schedule(|arg| arg.in_range(1000..2000))
+        .returns(true);
+
+    scheduler.schedule(2000);
+}
+
+#[cfg(rustc_is_nightly)]
+#[test]
+#[should_panic(expected = "schedule(3..=7) -> true exactly 1 time")]
+fn range_matchers_are_displayed_in_failed_calls() {
+    let mut scheduler = SchedulerMock::new();
+    scheduler
+        .expect_schedule(|arg| arg.in_range(3..=7))
+        .times(1)
+        .returns(true);
+}
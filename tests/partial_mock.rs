@@ -0,0 +1,130 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Repository {
+    fn find(&self, id: u32) -> Option<String>;
+
+    fn insert(&mut self, id: u32, name: &str);
+
+    fn name(&self) -> &str;
+
+    fn convert<T>(&self, value: T) -> String
+    where
+        T: 'static;
+}
+
+#[derive(Default)]
+struct InMemoryRepository {
+    entries: Vec<(u32, String)>,
+}
+
+impl Repository for InMemoryRepository {
+    fn find(&self, id: u32) -> Option<String> {
+        self.entries
+            .iter()
+            .find(|(entry_id, _)| *entry_id == id)
+            .map(|(_, name)| name.clone())
+    }
+
+    fn insert(&mut self, id: u32, name: &str) {
+        self.entries.push((id, name.to_string()));
+    }
+
+    fn name(&self) -> &str {
+        "in-memory"
+    }
+
+    fn convert<T>(&self, _value: T) -> String
+    where
+        T: 'static,
+    {
+        String::from("converted")
+    }
+}
+
+#[mockable(sync)]
+trait Counter {
+    fn increment(&self) -> u32;
+}
+
+#[derive(Default)]
+struct AtomicCounter(std::sync::atomic::AtomicU32);
+
+impl Counter for AtomicCounter {
+    fn increment(&self) -> u32 {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+    }
+}
+
+#[test]
+fn unmatched_calls_are_forwarded_to_fallback() {
+    let mut repository = RepositoryMock::with_fallback(InMemoryRepository::default());
+
+    repository.insert(1, "Jane");
+
+    assert_eq!(Some(String::from("Jane")), repository.find(1));
+    assert_eq!(None, repository.find(2));
+    assert!(repository.verify().is_ok());
+}
+
+#[test]
+fn expected_calls_take_precedence_over_fallback() {
+    let mut repository = RepositoryMock::with_fallback(InMemoryRepository::default());
+    repository
+        .expect_find(|arg| arg.partial_eq(1))
+        .returns(Some(String::from("John")));
+
+    repository.insert(1, "Jane");
+
+    assert_eq!(Some(String::from("John")), repository.find(1));
+}
+
+#[test]
+fn expected_calls_can_call_through() {
+    let mut repository = RepositoryMock::with_fallback(InMemoryRepository::default());
+    repository
+        .expect_insert(|arg| arg.partial_eq(1), |arg| arg.any())
+        .times(1)
+        .calls_through();
+
+    repository.insert(1, "Jane");
+
+    assert_eq!(Some(String::from("Jane")), repository.find(1));
+    repository.calls_to_insert().assert_called_times(1);
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn methods_returning_borrowed_data_are_not_forwarded() {
+    let repository = RepositoryMock::with_fallback(InMemoryRepository::default());
+
+    repository.name();
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn generic_methods_are_not_forwarded() {
+    let repository = RepositoryMock::with_fallback(InMemoryRepository::default());
+
+    repository.convert(42_u32);
+}
+
+#[test]
+#[should_panic(expected = "no real implementation is available")]
+fn calls_through_panics_without_fallback() {
+    let mut repository = RepositoryMock::new();
+
+    repository.expect_find(|arg| arg.any()).calls_through();
+}
+
+#[test]
+fn thread_safe_mocks_can_have_fallback() {
+    let counter = CounterMock::with_fallback(AtomicCounter::default());
+
+    std::thread::spawn(move || {
+        assert_eq!(1, counter.increment());
+        assert_eq!(2, counter.increment());
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,168 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[cfg_attr(test, mockable)]
+trait Repository<T>
+where
+    T: Debug,
+{
+    fn save(&self, item: &T) -> bool;
+
+    fn save_all(&self, items: &[T]) -> usize;
+
+    fn find(&self, ids: Vec<&T>) -> Option<String>;
+}
+
+#[test]
+fn reference_to_generic_type_works() {
+    let mut repository = RepositoryMock::<u32>::new();
+
+    repository
+        .expect_save(|arg| arg.partial_eq(&42))
+        .returns(true);
+
+    assert!(repository.save(&42));
+}
+
+#[test]
+fn slice_of_generic_type_works() {
+    let mut repository = RepositoryMock::<u32>::new();
+
+    repository
+        .expect_save_all(|arg| arg.any())
+        .returns_with(|args| args.items.len());
+
+    assert_eq!(3, repository.save_all(&[1, 2, 3]));
+}
+
+#[test]
+fn container_of_references_to_generic_type_works() {
+    let mut repository = RepositoryMock::<u32>::new();
+
+    repository
+        .expect_find(|arg| arg.any())
+        .returns_with(|args| Some(format!("{:?}", args.ids)));
+
+    assert_eq!(Some(String::from("[1, 2]")), repository.find(vec![&1, &2]));
+}
+
+#[test]
+fn generic_type_does_not_need_to_be_static() {
+    let name = String::from("foo");
+    let mut repository = RepositoryMock::<&str>::new();
+
+    repository
+        .expect_save(|arg| arg.any())
+        .returns_with(|args| args.item.len() == 3);
+
+    assert!(repository.save(&name.as_str()));
+}
+
+#[cfg_attr(test, mockable(sync))]
+trait Cache<K, V> {
+    fn insert(&self, key: &K, value: &V);
+}
+
+#[test]
+fn references_to_generic_types_work_with_thread_safe_mocks() {
+    let mut cache = CacheMock::<u8, String>::new();
+
+    cache
+        .expect_insert(|arg| arg.partial_eq(&1), |arg| arg.any())
+        .returns(());
+
+    let cache: Box<dyn Cache<u8, String> + Send + Sync> = Box::new(cache);
+
+    cache.insert(&1, &String::from("one"));
+}
+
+#[cfg_attr(test, mockable)]
+trait Lookup<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+
+    fn first(&self) -> &V;
+}
+
+#[test]
+fn reference_to_generic_type_can_be_returned() {
+    let one = String::from("one");
+    let mut lookup = LookupMock::<u8, String>::new();
+
+    lookup
+        .expect_get(|arg| arg.partial_eq(&1))
+        .returns(Some(&one));
+    lookup.expect_get(|arg| arg.partial_eq(&2)).returns(None);
+    lookup.expect_first().returns(&one);
+
+    assert_eq!(Some(&one), lookup.get(&1));
+    assert_eq!(None, lookup.get(&2));
+    assert_eq!("one", lookup.first());
+}
+
+#[cfg_attr(test, mockable(sync))]
+trait SharedLookup<V> {
+    fn get(&self, key: &str) -> Option<&V>;
+}
+
+#[test]
+fn reference_to_generic_type_can_be_returned_by_thread_safe_mocks() {
+    let mut lookup = SharedLookupMock::<u32>::new();
+
+    lookup
+        .expect_get(|arg| arg.partial_eq("answer"))
+        .returns(Some(&42));
+
+    let lookup: Box<dyn SharedLookup<u32> + Send + Sync> = Box::new(lookup);
+
+    assert_eq!(Some(&42), lookup.get("answer"));
+}
+
+trait Shape {
+    type Output: Debug;
+}
+
+struct Square;
+
+impl Shape for Square {
+    type Output = u32;
+}
+
+#[cfg_attr(test, mockable)]
+trait Renderer<T, U, V>
+where
+    T: Debug,
+    U: Debug,
+    V: Shape,
+{
+    fn render_all(&self, items: &[T]) -> String;
+
+    fn render_each(&self, items: Vec<&U>) -> String;
+
+    fn render_output(&self, output: &V::Output) -> String;
+
+    fn shape(&self) -> &V;
+}
+
+#[test]
+fn references_to_generic_types_from_where_clause_work() {
+    let square = Square;
+    let mut renderer = RendererMock::<u8, String, Square>::new();
+
+    renderer
+        .expect_render_all(|arg| arg.any())
+        .returns_with(|args| format!("{:?}", args.items));
+    renderer
+        .expect_render_each(|arg| arg.any())
+        .returns_with(|args| format!("{:?}", args.items));
+    renderer
+        .expect_render_output(|arg| arg.partial_eq(&4))
+        .returns(String::from("4"));
+    renderer.expect_shape().returns(&square);
+
+    let one = String::from("one");
+
+    assert_eq!("[1, 2]", renderer.render_all(&[1, 2]));
+    assert_eq!("[\"one\"]", renderer.render_each(vec![&one]));
+    assert_eq!("4", renderer.render_output(&4));
+    let _: &Square = renderer.shape();
+}
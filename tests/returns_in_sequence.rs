@@ -0,0 +1,85 @@
+use mockiato::mockable;
+
+#[derive(Debug, PartialEq)]
+struct Message(&'static str);
+
+#[mockable]
+trait MessageGenerator {
+    fn generate_message(&self) -> Message;
+}
+
+#[test]
+fn mocked_method_returns_values_in_order() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .times(3)
+        .returns_in_sequence(vec![Message("a"), Message("b"), Message("c")]);
+
+    assert_eq!(Message("a"), message_generator.generate_message());
+    assert_eq!(Message("b"), message_generator.generate_message());
+    assert_eq!(Message("c"), message_generator.generate_message());
+}
+
+#[test]
+fn times_can_be_specified_after_return_values() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .returns_in_sequence(vec![Message("a"), Message("b")])
+        .times(1..=2);
+
+    assert_eq!(Message("a"), message_generator.generate_message());
+}
+
+#[test]
+#[should_panic(expected = "All values of the sequence were already returned")]
+fn mocked_method_panics_when_invoked_more_often_than_there_are_values() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .times(..)
+        .returns_in_sequence(vec![Message("a")]);
+
+    assert_eq!(Message("a"), message_generator.generate_message());
+    let _ = message_generator.generate_message();
+}
+
+#[test]
+#[should_panic(expected = "The call MessageGeneratorMock::generate_message() was not expected.")]
+fn calls_exceeding_times_are_rejected_before_the_sequence_is_exhausted() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .times(1)
+        .returns_in_sequence(vec![Message("a"), Message("b")]);
+
+    assert_eq!(Message("a"), message_generator.generate_message());
+    let _ = message_generator.generate_message();
+}
+
+#[test]
+#[should_panic(
+    expected = "Return value can only be returned 2 times but call was expected \
+                           exactly 3 times."
+)]
+fn setup_of_method_panics_when_times_exceeds_number_of_values() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .returns_in_sequence(vec![Message("a"), Message("b")])
+        .times(3);
+}
+
+#[test]
+#[should_panic(
+    expected = "Return value can only be returned 2 times but call was expected \
+                           at least 3 times."
+)]
+fn setup_of_method_panics_when_lower_limit_of_times_exceeds_number_of_values() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .times(3..)
+        .returns_in_sequence(vec![Message("a"), Message("b")]);
+}
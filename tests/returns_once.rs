@@ -42,6 +42,16 @@ fn setup_of_method_panics_when_times_is_specified_before_return_value() {
         .returns_once(Message);
 }
 
+#[test]
+#[should_panic(expected = "Return value can only be returned once but call was expected")]
+fn setup_of_method_panics_when_times_is_a_range() {
+    let mut message_generator = MessageGeneratorMock::new();
+    message_generator
+        .expect_generate_message()
+        .times(..=1)
+        .returns_once(Message);
+}
+
 fn message_generator_mock() -> Box<dyn MessageGenerator> {
     let mut message_generator = MessageGeneratorMock::new();
     message_generator.expect_generate_message_calls_in_order();
@@ -0,0 +1,62 @@
+use mockiato::mockable;
+
+#[derive(Debug, PartialEq)]
+struct Greeting(String);
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> Greeting;
+
+    fn greet_two_people(&self, first_name: &str, second_name: &str) -> Greeting;
+}
+
+#[test]
+fn return_value_is_computed_from_arguments() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns_with(|args| Greeting(format!("Hello {}", args.name)));
+
+    assert_eq!(Greeting(String::from("Hello Jane")), greeter.greet("Jane"));
+    assert_eq!(Greeting(String::from("Hello John")), greeter.greet("John"));
+}
+
+#[test]
+fn closure_receives_all_arguments() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet_two_people(|arg| arg.any(), |arg| arg.any())
+        .returns_with(|args| {
+            Greeting(format!(
+                "Hello {} and {}",
+                args.first_name, args.second_name
+            ))
+        });
+
+    assert_eq!(
+        Greeting(String::from("Hello Jane and John")),
+        greeter.greet_two_people("Jane", "John")
+    );
+}
+
+mod messenger {
+    use mockiato::mockable;
+
+    #[mockable]
+    pub(super) trait Messenger {
+        fn send(&self, message: String) -> usize;
+    }
+}
+
+#[test]
+fn arguments_can_be_accessed_outside_of_the_module_of_the_trait() {
+    use self::messenger::{Messenger, MessengerMock};
+
+    let mut messenger = MessengerMock::new();
+    messenger
+        .expect_send(|arg| arg.any())
+        .returns_with(|args| args.message.len());
+
+    assert_eq!(5, messenger.send(String::from("Hello")));
+}
@@ -0,0 +1,107 @@
+use mockiato::{mockable, Sequence};
+
+#[mockable]
+trait File {
+    fn open(&self, path: &str);
+
+    fn write(&self, content: &str);
+
+    fn close(&self);
+}
+
+#[mockable]
+trait Logger {
+    fn log(&self, message: &str);
+}
+
+#[test]
+fn calls_to_different_methods_can_be_ordered() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+
+    file.expect_open(|arg| arg.partial_eq("foo.txt"))
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_write(|arg| arg.any())
+        .times(2)
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+
+    file.open("foo.txt");
+    file.write("foo");
+    file.write("bar");
+    file.close();
+}
+
+#[test]
+fn calls_on_different_mocks_can_be_ordered() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(|arg| arg.partial_eq("opening"))
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+    logger
+        .expect_log(|arg| arg.partial_eq("opened"))
+        .in_sequence(&sequence)
+        .returns(());
+
+    logger.log("opening");
+    file.open("foo.txt");
+    logger.log("opened");
+}
+
+#[test]
+fn calls_outside_of_the_sequence_are_not_ordered() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+    let mut logger = LoggerMock::new();
+
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+    logger.expect_log(|arg| arg.any()).returns(());
+
+    file.open("foo.txt");
+    file.close();
+    logger.log("closed");
+}
+
+#[test]
+#[should_panic(expected = "Expected call #1 of the sequence: FileMock::open(")]
+fn call_before_its_turn_panics() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+
+    file.close();
+}
+
+#[test]
+#[should_panic(expected = "Actual call #1 of the sequence")]
+fn call_after_a_later_call_panics() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(|arg| arg.any())
+        .times(..)
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+
+    file.close();
+    logger.log("closed");
+}
@@ -0,0 +1,137 @@
+use mockiato::mockable;
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    name: &'static str,
+}
+
+#[cfg_attr(test, mockable)]
+trait Service {
+    fn new(config: Config) -> Self;
+
+    fn name() -> &'static str;
+
+    fn run(&self, input: u32) -> u32;
+}
+
+fn create_and_run<S: Service>(input: u32) -> (String, u32) {
+    let service = S::new(Config { name: "test" });
+    (S::name().to_string(), service.run(input))
+}
+
+#[test]
+fn static_methods_can_be_mocked() {
+    let mut statics = ServiceMockStatics::new();
+
+    statics.expect_name().returns("mock");
+    statics
+        .expect_new(|arg| arg.partial_eq(Config { name: "test" }))
+        .returns_with(|_| {
+            let mut service = ServiceMock::new();
+            service.expect_run(|arg| arg.partial_eq(2)).returns(4);
+            service
+        });
+
+    let _statics = statics.register();
+
+    assert_eq!((String::from("mock"), 4), create_and_run::<ServiceMock>(2));
+}
+
+#[test]
+#[should_panic(expected = "The call to ServiceMock::name was not expected")]
+fn calling_static_method_without_registered_expectations_panics() {
+    ServiceMock::name();
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for ServiceMock::name were not satisified.")]
+fn expectations_are_verified_when_guard_is_dropped() {
+    let mut statics = ServiceMockStatics::new();
+
+    statics.expect_name().returns("mock");
+
+    let _statics = statics.register();
+}
+
+#[test]
+fn static_methods_can_expect_calls_in_order() {
+    let mut statics = ServiceMockStatics::new();
+
+    statics.expect_name_calls_in_order();
+    statics.expect_name().returns("first");
+    statics.expect_name().returns("second");
+
+    let _statics = statics.register();
+
+    assert_eq!("first", ServiceMock::name());
+    assert_eq!("second", ServiceMock::name());
+}
+
+#[test]
+fn expectations_can_be_registered_again_after_the_guard_was_dropped() {
+    for name in &["first", "second"] {
+        let mut statics = ServiceMockStatics::new();
+        statics.expect_name().returns(*name);
+        let _statics = statics.register();
+
+        assert_eq!(*name, ServiceMock::name());
+    }
+}
+
+#[cfg_attr(test, mockable)]
+trait Factory<T> {
+    fn create(value: T) -> Option<T>;
+}
+
+#[test]
+fn static_methods_work_with_generic_traits() {
+    let mut statics = FactoryMockStatics::<u8>::new();
+
+    statics
+        .expect_create(|arg| arg.partial_eq(1))
+        .returns_with(|args| Some(args.value + 1));
+
+    let _statics = statics.register();
+
+    assert_eq!(Some(2), FactoryMock::<u8>::create(1));
+}
+
+#[cfg_attr(test, mockable)]
+trait Parser {
+    fn parse<T: 'static>(input: &str) -> T;
+}
+
+#[test]
+fn generic_static_methods_can_be_mocked() {
+    let mut statics = ParserMockStatics::new();
+
+    statics
+        .expect_parse::<u32>(|arg| arg.partial_eq("42"))
+        .returns(42);
+    statics
+        .expect_parse::<bool>(|arg| arg.partial_eq("true"))
+        .returns(true);
+
+    let _statics = statics.register();
+
+    assert_eq!(42_u32, ParserMock::parse("42"));
+    assert!(ParserMock::parse::<bool>("true"));
+}
+
+#[cfg_attr(test, mockable(object_safe))]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+}
+
+#[test]
+fn object_safe_traits_can_be_used_as_trait_objects() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    let greeter: Box<dyn Greeter> = Box::new(greeter);
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+}
@@ -0,0 +1,58 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Database {
+    fn execute(&self, query: &str) -> u32;
+
+    fn log(&self, line: String);
+
+    #[allow(clippy::ptr_arg)]
+    fn open(&self, path: &String);
+}
+
+#[test]
+fn string_matchers_match_str_arguments() {
+    let mut database = DatabaseMock::new();
+    database
+        .expect_execute(|arg| arg.starts_with("SELECT"))
+        .returns(1);
+    database
+        .expect_execute(|arg| arg.eq_ignore_ascii_case("delete from users"))
+        .returns(2);
+
+    assert_eq!(1, database.execute("SELECT * FROM users"));
+    assert_eq!(2, database.execute("DELETE FROM users"));
+}
+
+#[test]
+fn string_matchers_match_string_arguments() {
+    let mut database = DatabaseMock::new();
+    database
+        .expect_log(|arg| arg.contains("failed"))
+        .returns(());
+    database.expect_open(|arg| arg.ends_with(".db")).returns(());
+
+    database.log(String::from("connection failed"));
+    database.open(&String::from("users.db"));
+}
+
+#[test]
+fn glob_matches_wildcards() {
+    let mut database = DatabaseMock::new();
+    database
+        .expect_execute(|arg| arg.glob("SELECT * FROM user_?"))
+        .returns(1);
+
+    assert_eq!(1, database.execute("SELECT id FROM user_a"));
+}
+
+#[test]
+#[should_panic(expected = "execute(glob(\"SELECT * FROM user_?\"))")]
+fn string_matchers_are_displayed_in_failed_calls() {
+    let mut database = DatabaseMock::new();
+    database
+        .expect_execute(|arg| arg.glob("SELECT * FROM user_?"))
+        .returns(1);
+
+    database.execute("SELECT id FROM users");
+}
@@ -0,0 +1,102 @@
+use mockiato::mockable;
+use std::sync::Arc;
+use std::thread;
+
+#[cfg_attr(test, mockable(sync))]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+}
+
+#[test]
+fn mock_can_be_shared_between_threads() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(2)
+        .returns(String::from("Hello Jane"));
+
+    let greeter: Arc<dyn Greeter + Send + Sync> = Arc::new(greeter);
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let greeter = greeter.clone();
+            thread::spawn(move || greeter.greet("Jane"))
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!("Hello Jane", handle.join().unwrap());
+    }
+}
+
+#[test]
+fn computed_return_values_work_across_threads() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(3)
+        .returns_with(|args| format!("Hello {}", args.name));
+
+    let greeter = Arc::new(greeter);
+
+    let handles: Vec<_> = ["Jane", "John", "Joe"]
+        .iter()
+        .map(|name| {
+            let greeter = greeter.clone();
+            thread::spawn(move || greeter.greet(name))
+        })
+        .collect();
+
+    let mut greetings: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    greetings.sort();
+
+    assert_eq!(vec!["Hello Jane", "Hello Joe", "Hello John"], greetings);
+}
+
+#[test]
+fn sequenced_return_values_work_across_threads() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns_in_sequence(vec![String::from("Hello"), String::from("Hi")]);
+
+    let greeter = Arc::new(greeter);
+
+    let mut greetings: Vec<_> = (0..2)
+        .map(|_| {
+            let greeter = greeter.clone();
+            thread::spawn(move || greeter.greet("Jane"))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    greetings.sort();
+
+    assert_eq!(vec!["Hello", "Hi"], greetings);
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::greet were not satisified.")]
+fn calls_are_verified_when_last_reference_is_dropped() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(2)
+        .returns(String::from("Hello Jane"));
+
+    let greeter = Arc::new(greeter);
+
+    {
+        let greeter = greeter.clone();
+        thread::spawn(move || greeter.greet("Jane")).join().unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(dead_code, clippy::missing_safety_doc)]
+
 use mockiato::mockable;
 
 #[mockable]
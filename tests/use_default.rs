@@ -0,0 +1,67 @@
+use mockiato::mockable;
+
+#[mockable(use_default(greet_all))]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn greet_all(&self, names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| self.greet(name)).collect()
+    }
+
+    fn farewell(&self, name: &str) -> String {
+        format!("Bye {}", name)
+    }
+}
+
+#[mockable(use_default)]
+trait Logger {
+    fn log(&self, message: &str);
+
+    fn info(&self, message: &str) {
+        self.log(&format!("INFO: {}", message));
+    }
+
+    fn warn(&self, message: &str) {
+        self.log(&format!("WARN: {}", message));
+    }
+}
+
+#[test]
+fn listed_methods_use_default_implementation() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    greeter
+        .expect_greet(|arg| arg.partial_eq("John"))
+        .returns(String::from("Hello John"));
+
+    assert_eq!(
+        vec![String::from("Hello Jane"), String::from("Hello John")],
+        greeter.greet_all(&["Jane", "John"])
+    );
+}
+
+#[test]
+fn methods_with_default_implementation_can_still_be_mocked() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_farewell(|arg| arg.any())
+        .returns(String::from("See you"));
+
+    assert_eq!("See you", greeter.farewell("Jane"));
+}
+
+#[test]
+fn all_methods_with_default_implementation_use_it() {
+    let mut logger = LoggerMock::new();
+    logger
+        .expect_log(|arg| arg.partial_eq("INFO: started"))
+        .returns(());
+    logger
+        .expect_log(|arg| arg.partial_eq("WARN: stopped"))
+        .returns(());
+
+    logger.info("started");
+    logger.warn("stopped");
+}
@@ -0,0 +1,83 @@
+use mockiato::{mockable, CallErrorKind};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn leave(&self);
+}
+
+#[test]
+fn verify_is_ok_if_expected_calls_were_made() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Jane");
+
+    assert!(greeter.verify().is_ok());
+}
+
+#[test]
+fn verify_reports_unsatisfied_expected_calls() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(2)
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Jane");
+
+    let error = greeter.verify().unwrap_err();
+
+    assert!(error.call_errors().is_empty());
+
+    let verification_error = &error.verification_errors()[0];
+    assert_eq!("GreeterMock::greet", verification_error.method_name());
+
+    let expected_call = &verification_error.expected_calls()[0];
+    assert_eq!(
+        &mockiato::ExpectedCalls::from(2),
+        expected_call.expected_calls()
+    );
+    assert_eq!(1, expected_call.actual_number_of_calls());
+    assert!(!expected_call.is_satisfied());
+
+    greeter.greet("Jane");
+}
+
+#[test]
+fn verify_reports_unexpected_calls() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    greeter.expect_leave().returns(());
+
+    let result = catch_unwind(AssertUnwindSafe(|| greeter.greet("John")));
+    assert!(result.is_err());
+
+    let error = greeter.verify().unwrap_err();
+
+    let call_error = &error.call_errors()[0];
+    assert_eq!("GreeterMock::greet", call_error.method_name());
+    match call_error.kind() {
+        CallErrorKind::NoMatchingCall { expected_calls } => assert_eq!(1, expected_calls.len()),
+        kind => panic!("Unexpected call error: {:?}", kind),
+    }
+
+    let method_names: Vec<_> = error
+        .verification_errors()
+        .iter()
+        .map(|error| error.method_name())
+        .collect();
+    assert_eq!(
+        vec!["GreeterMock::greet", "GreeterMock::leave"],
+        method_names
+    );
+
+    greeter.greet("Jane");
+    greeter.leave();
+}
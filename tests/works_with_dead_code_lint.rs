@@ -1,4 +1,5 @@
 #![deny(dead_code)]
+#![allow(clippy::disallowed_names)]
 
 use mockiato::mockable;
 